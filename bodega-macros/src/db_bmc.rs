@@ -1,8 +1,10 @@
-use darling::{FromDeriveInput, FromMeta};
-use heck::ToSnakeCase;
+use darling::{ast::NestedMeta, FromDeriveInput, FromMeta};
+use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_quote, spanned::Spanned, DeriveInput, Ident, Path, Visibility};
+use quote::{quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned, DeriveInput, Expr, Ident, Meta, Path, Visibility};
+
+use crate::helpers::{pluralize, ParenArgs};
 
 #[derive(Debug, Clone, FromDeriveInput)]
 #[darling(attributes(db_bmc), supports(any))]
@@ -17,18 +19,39 @@ pub(crate) struct BmcArgs {
     #[darling(default)]
     table_name: Option<String>,
 
+    #[darling(default)]
+    schema: Option<String>,
+
+    #[darling(default)]
+    table_alias: Option<String>,
+
     #[darling(default)]
     iden_enum: Option<Path>,
 
     #[darling(default)]
     id_iden: Option<Path>,
 
+    #[darling(default)]
+    model_id_field: Option<String>,
+
+    #[darling(default)]
+    id_column: Option<String>,
+
     #[darling(default)]
     error: Option<Path>,
 
     #[darling(default)]
     private_methods: bool,
 
+    #[darling(default)]
+    auto_filter: Option<Path>,
+
+    #[darling(default)]
+    soft_delete: Option<SoftDeleteArgs>,
+
+    #[darling(default)]
+    timestamps: Option<TimestampsArgs>,
+
     #[darling(default)]
     methods: MethodArgs,
 }
@@ -36,25 +59,352 @@ pub(crate) struct BmcArgs {
 #[derive(Debug, Default, Clone, FromMeta)]
 pub(crate) struct MethodArgs {
     #[darling(default)]
-    create: Option<Path>,
+    create: Option<Method<Path>>,
+
+    #[darling(default)]
+    create_many: Option<Method<Path>>,
+
+    #[darling(default)]
+    get: Option<Method<()>>,
+
+    #[darling(default)]
+    get_arc: Option<Method<()>>,
+
+    #[darling(default)]
+    get_optional: Option<Method<()>>,
+
+    #[darling(default)]
+    get_many: Option<Method<()>>,
+
+    #[darling(default)]
+    get_many_optional: Option<Method<()>>,
+
+    #[darling(default)]
+    get_many_by_ids: Option<Method<()>>,
+
+    #[darling(default)]
+    find: Option<Method<Path>>,
+
+    #[darling(default)]
+    find_optional: Option<Method<Path>>,
+
+    #[darling(default)]
+    first: Option<Method<()>>,
+
+    #[darling(default)]
+    last: Option<Method<()>>,
+
+    #[darling(default)]
+    list: Option<Method<()>>,
+
+    #[darling(default)]
+    list_ordered: Option<Method<()>>,
+
+    #[darling(default)]
+    list_filtered: Option<Method<Path>>,
+
+    #[darling(default)]
+    list_paginated: Option<Method<ListPaginatedArgs>>,
+
+    #[darling(default)]
+    list_by_cursor: Option<Method<()>>,
+
+    #[darling(default)]
+    list_offset_paginated: Option<Method<Path>>,
+
+    #[darling(default)]
+    update: Option<Method<Path>>,
+
+    #[darling(default)]
+    update_conditional: Option<Method<()>>,
+
+    #[darling(default)]
+    update_all_where: Option<Method<ParenArgs<UpdateWhereArgs>>>,
+
+    #[darling(default)]
+    update_where: Option<Method<UpdateWhereReturningArgs>>,
+
+    #[darling(default)]
+    upsert: Option<Method<UpsertArgs>>,
+
+    #[darling(default)]
+    upsert_by: Option<Method<ParenArgs<UpsertByArgs>>>,
+
+    #[darling(default)]
+    delete: Option<Method<()>>,
+
+    #[darling(default)]
+    delete_where: Option<Method<Path>>,
+
+    #[darling(default)]
+    batch_delete_where: Option<Method<Path>>,
+
+    #[darling(default)]
+    count: Option<Method<()>>,
+
+    #[darling(default)]
+    count_filtered: Option<Method<Path>>,
+
+    #[darling(default)]
+    exists: Option<Method<()>>,
 
     #[darling(default)]
-    get: bool,
+    exists_where: Option<Method<Path>>,
+}
+
+/// The configuration of a generated method, being the usual value for the
+/// method, like `create = BookCreate`, or the same in a list alongside a
+/// visibility, like `create(BookCreate, vis = "pub(crate)")`.
+#[derive(Debug, Clone)]
+pub(crate) struct Method<T> {
+    value: T,
+    vis: Option<Visibility>,
+}
+
+impl<T: FromMeta> FromMeta for Method<T> {
+    fn from_meta(item: &Meta) -> darling::Result<Self> {
+        let Meta::List(list) = item else {
+            return T::from_meta(item).map(|value| Self { value, vis: None });
+        };
+
+        let mut vis = None;
+        let mut rest = Vec::new();
+
+        for nested in NestedMeta::parse_meta_list(list.tokens.clone())? {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(ref nv)) if nv.path.is_ident("vis") => {
+                    if vis.is_some() {
+                        return Err(darling::Error::duplicate_field("vis").with_span(nv));
+                    }
+
+                    let raw = String::from_expr(&nv.value)?;
+                    vis = Some(
+                        syn::parse_str::<Visibility>(&raw)
+                            .map_err(|e| darling::Error::custom(e).with_span(&nv.value))?,
+                    );
+                }
+                other => rest.push(other),
+            }
+        }
+
+        let paths = rest
+            .iter()
+            .map(|nested| match nested {
+                NestedMeta::Meta(Meta::Path(path)) => Some(path),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        // bare paths are the value as it would be given after the `=`, while
+        // anything else is the value's own list form
+        let value = match paths {
+            _ if rest.is_empty() => T::from_word(),
+            Some(paths) if paths.len() == 1 => {
+                let path = paths[0];
+                T::from_expr(&parse_quote! { #path })
+            }
+            Some(paths) => T::from_expr(&parse_quote! { (#(#paths),*) }),
+            None => T::from_list(&rest),
+        }
+        .map_err(|e| e.with_span(item))?;
+
+        Ok(Self { value, vis })
+    }
+}
+
+/// Either `soft_delete` for the default `deleted_at` column, or
+/// `soft_delete = "column_name"`.
+#[derive(Debug, Clone)]
+pub(crate) struct SoftDeleteArgs(String);
+
+impl FromMeta for SoftDeleteArgs {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self("deleted_at".to_string()))
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        if value.is_empty() {
+            return Err(darling::Error::custom(
+                "soft_delete column name cannot be empty",
+            ));
+        }
+
+        Ok(Self(value.to_string()))
+    }
+}
+
+/// Either `timestamps` for the default `created_at` and `updated_at` columns,
+/// or `timestamps(created_at_col = "...", updated_at_col = "...")`.
+#[derive(Debug, Clone)]
+pub(crate) struct TimestampsArgs(TimestampsOpts);
 
+#[derive(Debug, Default, Clone, FromMeta)]
+pub(crate) struct TimestampsOpts {
     #[darling(default)]
-    list: bool,
+    created_at_col: Option<String>,
 
     #[darling(default)]
-    list_paginated: Option<Path>,
+    updated_at_col: Option<String>,
+}
+
+impl FromMeta for TimestampsArgs {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self(TimestampsOpts::default()))
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        TimestampsOpts::from_list(items).map(Self)
+    }
+}
+
+/// Either `list_paginated = FilterType` or
+/// `list_paginated = (filter = FilterType, order_by = ..., order = desc)`.
+#[derive(Debug, Clone)]
+pub(crate) struct ListPaginatedArgs(ListPaginatedOpts);
+
+impl FromMeta for ListPaginatedArgs {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        ListPaginatedOpts::from_list(items).map(Self)
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Path(path) => Ok(Self(ListPaginatedOpts {
+                filter: path.path.clone(),
+                order_by: None,
+                order: None,
+            })),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => ParenArgs::<ListPaginatedOpts>::from_expr(expr).map(|ParenArgs(opts)| Self(opts)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct ListPaginatedOpts {
+    filter: Path,
 
     #[darling(default)]
-    update: Option<Path>,
+    order_by: Option<Path>,
 
     #[darling(default)]
-    delete: bool,
+    order: Option<OrderArg>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum OrderArg {
+    Asc,
+    Desc,
+}
+
+impl FromMeta for OrderArg {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value.to_lowercase().as_str() {
+            "asc" => Ok(Self::Asc),
+            "desc" => Ok(Self::Desc),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Path(path) => match path.path.get_ident() {
+                Some(ident) => Self::from_string(&ident.to_string()).map_err(|e| e.with_span(expr)),
+                None => Err(darling::Error::unexpected_expr_type(expr).with_span(expr)),
+            },
+            Expr::Lit(lit) => Self::from_value(&lit.lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Err(darling::Error::unexpected_expr_type(expr).with_span(expr)),
+        }
+    }
+}
+
+/// `upsert = (CreateType, UpdateType)`
+#[derive(Debug, Clone)]
+pub(crate) struct UpsertArgs {
+    create: Path,
+    update: Path,
+}
+
+impl FromMeta for UpsertArgs {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        let (create, update) = path_pair(expr, "expected `(CreateType, UpdateType)`")?;
+
+        Ok(Self { create, update })
+    }
+}
+
+/// `update_where = (UpdateType, FilterType)`
+#[derive(Debug, Clone)]
+pub(crate) struct UpdateWhereReturningArgs {
+    update: Path,
+    filter: Path,
+}
+
+impl FromMeta for UpdateWhereReturningArgs {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        let (update, filter) = path_pair(expr, "expected `(UpdateType, FilterType)`")?;
+
+        Ok(Self { update, filter })
+    }
+}
 
+/// Parses a tuple of exactly two paths, like `(Foo, Bar)`.
+fn path_pair(expr: &Expr, expected: &str) -> darling::Result<(Path, Path)> {
+    match expr {
+        Expr::Tuple(tuple) => match (tuple.elems.len(), tuple.elems.first(), tuple.elems.last()) {
+            (2, Some(Expr::Path(first)), Some(Expr::Path(second))) => {
+                Ok((first.path.clone(), second.path.clone()))
+            }
+            _ => Err(darling::Error::custom(expected).with_span(expr)),
+        },
+        Expr::Group(group) => path_pair(&group.expr, expected),
+        _ => Err(darling::Error::unexpected_expr_type(expr).with_span(expr)),
+    }
+}
+
+/// `upsert_by = (conflict_cols = [...], update_type = ..., create_type = ...)`,
+/// where `create_type` defaults to the type given for `create`.
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct UpsertByArgs {
+    conflict_cols: PathArray,
+    update_type: Path,
     #[darling(default)]
-    count: bool,
+    create_type: Option<Path>,
+}
+
+/// An array of paths, like `[Foo::Bar, Foo::Baz]`.
+#[derive(Debug, Clone)]
+pub(crate) struct PathArray(Vec<Path>);
+
+impl FromMeta for PathArray {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Array(array) => array
+                .elems
+                .iter()
+                .map(|elem| match elem {
+                    Expr::Path(path) => Ok(path.path.clone()),
+                    _ => Err(darling::Error::unexpected_expr_type(elem).with_span(elem)),
+                })
+                .collect::<darling::Result<Vec<_>>>()
+                .and_then(|paths| {
+                    if paths.is_empty() {
+                        Err(darling::Error::custom("expected at least one column").with_span(expr))
+                    } else {
+                        Ok(Self(paths))
+                    }
+                }),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Err(darling::Error::unexpected_expr_type(expr).with_span(expr)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromMeta)]
+pub(crate) struct UpdateWhereArgs {
+    data: Path,
+    filter: Path,
 }
 
 pub fn db_bmc_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
@@ -68,6 +418,7 @@ pub fn db_bmc_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
     let mut out = quote! {};
 
     out.extend(info.trait_impl()?);
+    out.extend(info.filter_impl()?);
     out.extend(info.crud_methods()?);
 
     Ok(out.into())
@@ -94,15 +445,23 @@ impl<'a> ControllerInfo<'a> {
             })
             .ok_or_else(|| syn::Error::new(input.span(), "DbBmc: Failed to derive model name from model and was not provided a model_name as an argument."))?;
 
-        // stupid way to auto-compute this
-        let table_name = args.table_name.clone().unwrap_or_else(|| {
-            let raw = model_name.clone();
-            if raw.ends_with('s') {
-                raw
-            } else {
-                format!("{}s", raw)
-            }
-        });
+        let table_name = args
+            .table_name
+            .clone()
+            .unwrap_or_else(|| pluralize(&model_name));
+
+        let id_overrides = [
+            args.id_iden.is_some(),
+            args.model_id_field.is_some(),
+            args.id_column.is_some(),
+        ];
+
+        if id_overrides.iter().filter(|o| **o).count() > 1 {
+            return Err(syn::Error::new(
+                input.span(),
+                "DbBmc: Only one of id_iden, model_id_field, or id_column may be specified.",
+            ));
+        }
 
         let iden_enum = args.iden_enum.clone().unwrap_or_else(|| {
             let mut computed = args.model.clone();
@@ -132,53 +491,157 @@ impl<'a> ControllerInfo<'a> {
         let id_type = &self.args.id_type;
 
         let id_iden = self.args.id_iden.clone().unwrap_or_else(|| {
+            let variant = self
+                .args
+                .model_id_field
+                .as_deref()
+                .map(|f| f.to_upper_camel_case())
+                .unwrap_or_else(|| "Id".to_string());
+
             let mut computed = self.iden_enum.clone();
             computed.segments.push(syn::PathSegment {
-                ident: Ident::new("Id", self.iden_enum.span()),
+                ident: Ident::new(&variant, self.iden_enum.span()),
                 arguments: syn::PathArguments::None,
             });
 
             computed
         });
 
+        let (id_column, id_column_name) = match self.args.id_column {
+            Some(ref col) => (
+                quote! { sea_query::Alias::new(#col).into_iden() },
+                col.clone(),
+            ),
+            None => (
+                quote! { #id_iden.into_iden() },
+                self.args
+                    .model_id_field
+                    .clone()
+                    .unwrap_or_else(|| "id".to_string()),
+            ),
+        };
+
         let error = self
             .args
             .error
             .clone()
             .unwrap_or_else(|| parse_quote! { bodega::Error });
 
+        // without this, a bad id type results in a wall of unsatisfied bounds
+        // from the generated methods, rather than pointing at the attr.
+        let id_type_assertion = if self.input.generics.params.is_empty() {
+            quote_spanned! {id_type.span()=>
+                const _: fn() = || {
+                    fn assert_id_type<T: bodega::IdType>() {}
+
+                    assert_id_type::<#id_type>();
+                };
+            }
+        } else {
+            quote! {}
+        };
+
+        let table_schema = match self.args.schema {
+            Some(ref schema) => quote! {
+                const TABLE_SCHEMA: Option<&'static str> = Some(#schema);
+            },
+            None => quote! {},
+        };
+
+        let table_alias = match self.args.table_alias {
+            Some(ref alias) => quote! {
+                const TABLE_ALIAS: Option<&'static str> = Some(#alias);
+            },
+            None => quote! {},
+        };
+
+        let soft_delete_column = match self.args.soft_delete {
+            Some(SoftDeleteArgs(ref col)) => quote! {
+                const SOFT_DELETE_COLUMN: Option<&'static str> = Some(#col);
+            },
+            None => quote! {},
+        };
+
+        let timestamp_columns = match self.args.timestamps {
+            Some(TimestampsArgs(ref opts)) => {
+                let created_at = opts.created_at_col.as_deref().unwrap_or("created_at");
+                let updated_at = opts.updated_at_col.as_deref().unwrap_or("updated_at");
+
+                quote! {
+                    const CREATED_AT_COLUMN: Option<&'static str> = Some(#created_at);
+
+                    const UPDATED_AT_COLUMN: Option<&'static str> = Some(#updated_at);
+                }
+            }
+            None => quote! {},
+        };
+
         Ok(quote! {
+            #id_type_assertion
+
             #[automatically_derived]
             impl #impl_generics bodega::DbBmc for #name #ty_generics #where_clause {
                 const ENTITY: &'static str = #model_name;
 
                 const TABLE: &'static str = #table_name;
 
+                #table_schema
+
+                #table_alias
+
                 type Error = #error;
 
                 type IdType = #id_type;
 
                 fn id_column() -> sea_query::DynIden {
                     use sea_query::IntoIden;
-                    #id_iden.into_iden()
+                    #id_column
+                }
+
+                fn id_column_name() -> &'static str {
+                    #id_column_name
                 }
+
+                #soft_delete_column
+
+                #timestamp_columns
             }
         })
     }
 
-    fn fn_info(&self, basename: &'static str) -> (Option<Visibility>, Ident) {
-        if self.args.private_methods {
-            (
-                None,
-                Ident::new(&format!("_{}", basename), self.args.methods.create.span()),
-            )
-        } else {
-            (
-                Some(Visibility::Public(syn::token::Pub {
+    fn filter_impl(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let Some(filter) = self.args.auto_filter.as_ref() else {
+            return Ok(quote! {});
+        };
+
+        // if the user also has an impl for this type, the compiler will
+        // complain about conflicting implementations, pointing at both.
+        Ok(quote! {
+            #[automatically_derived]
+            impl bodega::Filter for #filter {
+                fn is_noop(&self) -> bool {
+                    true
+                }
+            }
+        })
+    }
+
+    /// The visibility and name of a generated method, where `vis` is the
+    /// visibility given for the method, if any, which takes precedence over
+    /// `private_methods`.
+    fn fn_info(&self, basename: &'static str, vis: Option<&Visibility>) -> (Visibility, Ident) {
+        match vis {
+            Some(vis) => (vis.clone(), Ident::new(basename, self.input.span())),
+            None if self.args.private_methods => (
+                Visibility::Inherited,
+                Ident::new(&format!("_{}", basename), self.input.span()),
+            ),
+            None => (
+                Visibility::Public(syn::token::Pub {
                     span: self.input.span(),
-                })),
-                Ident::new(basename, self.args.methods.create.span()),
-            )
+                }),
+                Ident::new(basename, self.input.span()),
+            ),
         }
     }
 
@@ -196,8 +659,9 @@ impl<'a> ControllerInfo<'a> {
 
         let mut out = quote! {};
 
-        if let Some(create_type) = self.args.methods.create.as_ref() {
-            let (vis, fn_name) = self.fn_info("create");
+        if let Some(method) = self.args.methods.create.as_ref() {
+            let create_type = &method.value;
+            let (vis, fn_name) = self.fn_info("create", method.vis.as_ref());
 
             out.extend(quote! {
                 #[automatically_derived]
@@ -215,8 +679,28 @@ impl<'a> ControllerInfo<'a> {
             });
         }
 
-        if self.args.methods.get {
-            let (vis, fn_name) = self.fn_info("get");
+        if let Some(method) = self.args.methods.create_many.as_ref() {
+            let create_type = &method.value;
+            let (vis, fn_name) = self.fn_info("create_many", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Create many rows in the database in a single statement, returning the created rows.
+                    #vis async fn #fn_name<X>(executor: &mut X, data: Vec<#create_type>) -> std::result::Result<Vec<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::create_many::<Self, _, _, _>(executor, data).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.get.as_ref() {
+            let (vis, fn_name) = self.fn_info("get", method.vis.as_ref());
 
             out.extend(quote! {
                 #[automatically_derived]
@@ -234,18 +718,37 @@ impl<'a> ControllerInfo<'a> {
             });
         }
 
-        if self.args.methods.list {
-            let (vis, fn_name) = self.fn_info("list");
+        if let Some(method) = self.args.methods.get_arc.as_ref() {
+            let (vis, fn_name) = self.fn_info("get_arc", method.vis.as_ref());
 
             out.extend(quote! {
                 #[automatically_derived]
                 impl #impl_generics #name #ty_generics #where_clause {
-                    /// Fetch all rows from the store.
-                    #vis async fn #fn_name<X>(executor: &mut X) -> std::result::Result<Vec<#model_type>, #error>
+                    /// Fetch a record from the store with the given id, wrapped in an `Arc`.
+                    #vis async fn #fn_name<X>(executor: &mut X, id: &#id_type) -> std::result::Result<std::sync::Arc<#model_type>, #error>
                     where
                         X: bodega::AsExecutor,
                     {
-                        let res = bodega::list::<Self, _, _>(executor).await?;
+                        let res = bodega::get::<Self, _, _>(executor, id).await?;
+
+                        Ok(std::sync::Arc::new(res))
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.get_optional.as_ref() {
+            let (vis, fn_name) = self.fn_info("get_optional", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch a record from the store with the given id, if it exists.
+                    #vis async fn #fn_name<X>(executor: &mut X, id: &#id_type) -> std::result::Result<Option<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::get_optional::<Self, _, _>(executor, id).await?;
 
                         Ok(res)
                     }
@@ -253,18 +756,18 @@ impl<'a> ControllerInfo<'a> {
             });
         }
 
-        if let Some(filters) = self.args.methods.list_paginated.as_ref() {
-            let (vis, fn_name) = self.fn_info("list_paginated");
+        if let Some(method) = self.args.methods.get_many.as_ref() {
+            let (vis, fn_name) = self.fn_info("get_many", method.vis.as_ref());
 
             out.extend(quote! {
                 #[automatically_derived]
                 impl #impl_generics #name #ty_generics #where_clause {
-                    /// Fetch all rows from the store.
-                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters) -> std::result::Result<bodega::Paginated<#model_type>, #error>
+                    /// Fetch the records from the store with the given ids, in the order of the ids, failing if any are missing.
+                    #vis async fn #fn_name<X>(executor: &mut X, ids: &[#id_type]) -> std::result::Result<Vec<#model_type>, #error>
                     where
                         X: bodega::AsExecutor,
                     {
-                        let res = bodega::list_paginated::<Self, _, _, _>(executor, filters).await?;
+                        let res = bodega::get_many::<Self, _, _>(executor, ids).await?;
 
                         Ok(res)
                     }
@@ -272,18 +775,18 @@ impl<'a> ControllerInfo<'a> {
             });
         }
 
-        if let Some(update_type) = self.args.methods.update.as_ref() {
-            let (vis, fn_name) = self.fn_info("update");
+        if let Some(method) = self.args.methods.get_many_optional.as_ref() {
+            let (vis, fn_name) = self.fn_info("get_many_optional", method.vis.as_ref());
 
             out.extend(quote! {
                 #[automatically_derived]
                 impl #impl_generics #name #ty_generics #where_clause {
-                    /// Create a row in the database, returning the created row.
-                    #vis async fn #fn_name<X>(executor: &mut X, id: &#id_type, data: #update_type) -> std::result::Result<#model_type, #error>
+                    /// Fetch the records from the store with the given ids, in the order of the ids, with `None` for any that are missing.
+                    #vis async fn #fn_name<X>(executor: &mut X, ids: &[#id_type]) -> std::result::Result<Vec<Option<#model_type>>, #error>
                     where
                         X: bodega::AsExecutor,
                     {
-                        let res = bodega::update::<Self, _, _, _>(executor, id, data).await?;
+                        let res = bodega::get_many_optional::<Self, _, _>(executor, ids).await?;
 
                         Ok(res)
                     }
@@ -291,37 +794,548 @@ impl<'a> ControllerInfo<'a> {
             });
         }
 
-        if self.args.methods.delete {
-            let (vis, fn_name) = self.fn_info("delete");
+        if let Some(method) = self.args.methods.get_many_by_ids.as_ref() {
+            let (vis, fn_name) = self.fn_info("get_many_by_ids", method.vis.as_ref());
 
             out.extend(quote! {
                 #[automatically_derived]
                 impl #impl_generics #name #ty_generics #where_clause {
-                    /// Delete the given record from the store.
-                    #vis async fn #fn_name<X>(executor: &mut X, id: &#id_type) -> std::result::Result<(), #error>
+                    /// Fetch all records from the store with any of the given ids, ignoring missing ids.
+                    #vis async fn #fn_name<X>(executor: &mut X, ids: &[#id_type]) -> std::result::Result<Vec<#model_type>, #error>
                     where
                         X: bodega::AsExecutor,
                     {
-                        bodega::delete::<Self, _>(executor, id).await?;
+                        let res = bodega::get_many_by_ids::<Self, _, _>(executor, ids).await?;
 
-                        Ok(())
+                        Ok(res)
                     }
                 }
             });
         }
 
-        if self.args.methods.count {
-            let (vis, fn_name) = self.fn_info("count");
+        if let Some(method) = self.args.methods.find.as_ref() {
+            let filters = &method.value;
+            let (vis, fn_name) = self.fn_info("find", method.vis.as_ref());
 
             out.extend(quote! {
                 #[automatically_derived]
                 impl #impl_generics #name #ty_generics #where_clause {
-                    /// Delete the given record from the store.
-                    #vis async fn #fn_name<X>(executor: &mut X) -> std::result::Result<usize, #error>
+                    /// Fetch a single record from the store that satisfies the filters.
+                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters) -> std::result::Result<#model_type, #error>
                     where
                         X: bodega::AsExecutor,
                     {
-                        let res = bodega::count::<Self, _>(executor).await?;
+                        let res = bodega::find::<Self, _, _, _>(executor, filters).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.find_optional.as_ref() {
+            let filters = &method.value;
+            let (vis, fn_name) = self.fn_info("find_optional", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch a single record from the store that satisfies the filters, if any.
+                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters) -> std::result::Result<Option<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::find_optional::<Self, _, _, _>(executor, filters).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.first.as_ref() {
+            let (vis, fn_name) = self.fn_info("first", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch the row with the lowest id from the store, if any.
+                    #vis async fn #fn_name<X>(executor: &mut X) -> std::result::Result<Option<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::first::<Self, _, _>(executor).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.last.as_ref() {
+            let (vis, fn_name) = self.fn_info("last", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch the row with the highest id from the store, if any.
+                    #vis async fn #fn_name<X>(executor: &mut X) -> std::result::Result<Option<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::last::<Self, _, _>(executor).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.list.as_ref() {
+            let (vis, fn_name) = self.fn_info("list", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch all rows from the store.
+                    #vis async fn #fn_name<X>(executor: &mut X) -> std::result::Result<Vec<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::list::<Self, _, _>(executor).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.list_ordered.as_ref() {
+            let (vis, fn_name) = self.fn_info("list_ordered", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch all rows from the store, ordered by each of the orderings in turn.
+                    #vis async fn #fn_name<X>(executor: &mut X, orderings: &[bodega::Ordering]) -> std::result::Result<Vec<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::list_ordered::<Self, _, _>(executor, orderings).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.list_filtered.as_ref() {
+            let filters = &method.value;
+            let (vis, fn_name) = self.fn_info("list_filtered", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch all rows from the store that satisfy the filters.
+                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters) -> std::result::Result<Vec<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::list_filtered::<Self, _, _, _>(executor, filters).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.list_paginated.as_ref() {
+            let ListPaginatedArgs(opts) = &method.value;
+            let (vis, fn_name) = self.fn_info("list_paginated", method.vis.as_ref());
+            let filters = &opts.filter;
+
+            let call = if opts.order_by.is_none() && opts.order.is_none() {
+                quote! { bodega::list_paginated::<Self, _, _, _>(executor, filters).await? }
+            } else {
+                let order_by = match opts.order_by {
+                    Some(ref col) => quote! { Some(sea_query::IntoIden::into_iden(#col)) },
+                    None => quote! { None },
+                };
+
+                let order = match opts.order {
+                    Some(OrderArg::Asc) => quote! { sea_query::Order::Asc },
+                    Some(OrderArg::Desc) => quote! { sea_query::Order::Desc },
+                    None => quote! { <#filters as bodega::CursoredFilter>::cursor_column_order() },
+                };
+
+                quote! {
+                    bodega::list_paginated_ordered::<Self, _, _, _>(executor, filters, #order_by, #order).await?
+                }
+            };
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch all rows from the store.
+                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters) -> std::result::Result<bodega::Paginated<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = #call;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.list_by_cursor.as_ref() {
+            let (vis, fn_name) = self.fn_info("list_by_cursor", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch a page of up to `limit` rows from the store after the given cursor.
+                    #vis async fn #fn_name<X>(executor: &mut X, cursor: Option<<#model_type as bodega::Cursored>::CursorType>, limit: usize) -> std::result::Result<bodega::Paginated<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::list_by_cursor::<Self, _, _>(executor, cursor, limit).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.list_offset_paginated.as_ref() {
+            let filters = &method.value;
+            let (vis, fn_name) = self.fn_info("list_offset_paginated", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Fetch a page of rows from the store that satisfy the filters by offset.
+                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters, page: usize, page_size: usize) -> std::result::Result<bodega::OffsetPaginated<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::list_offset_paginated::<Self, _, _, _>(executor, filters, page, page_size).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.update.as_ref() {
+            let update_type = &method.value;
+            let (vis, fn_name) = self.fn_info("update", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Create a row in the database, returning the created row.
+                    #vis async fn #fn_name<X>(executor: &mut X, id: &#id_type, data: #update_type) -> std::result::Result<#model_type, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::update::<Self, _, _, _>(executor, id, data).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.update_conditional.as_ref() {
+            let (vis, fn_name) = self.fn_info("update_conditional", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Update a row in the database with the values set by `f`, returning the updated row.
+                    #vis async fn #fn_name<X, F>(executor: &mut X, id: &#id_type, f: F) -> std::result::Result<#model_type, #error>
+                    where
+                        X: bodega::AsExecutor,
+                        F: FnOnce(&mut bodega::UpdateBuilder) -> bodega::Result<()>,
+                    {
+                        let res = bodega::update_conditional::<Self, _, _, _>(executor, id, f).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.update_all_where.as_ref() {
+            let ParenArgs(update_where) = &method.value;
+            let (vis, fn_name) = self.fn_info("update_all_where", method.vis.as_ref());
+            let update_type = &update_where.data;
+            let filters = &update_where.filter;
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Update all rows matching the filters, returning the number of rows updated.
+                    #vis async fn #fn_name<X>(executor: &mut X, data: #update_type, filters: &#filters) -> std::result::Result<u64, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::update_all_where::<Self, _, _, _>(executor, data, filters).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.update_where.as_ref() {
+            let update_where = &method.value;
+            let (vis, fn_name) = self.fn_info("update_where", method.vis.as_ref());
+            let update_type = &update_where.update;
+            let filters = &update_where.filter;
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Update all rows matching the filters, returning the updated rows.
+                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters, data: #update_type) -> std::result::Result<Vec<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::update_where::<Self, _, _, _, _>(executor, filters, data).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.upsert.as_ref() {
+            let upsert = &method.value;
+            let (vis, fn_name) = self.fn_info("upsert", method.vis.as_ref());
+            let create_type = &upsert.create;
+            let update_type = &upsert.update;
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Create a row in the database, or update it if it conflicts on `conflict_cols`, returning the resulting row.
+                    #vis async fn #fn_name<X>(executor: &mut X, data: #create_type, conflict_cols: &[sea_query::DynIden], update: #update_type) -> std::result::Result<#model_type, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::upsert::<Self, _, _, _, _>(executor, data, conflict_cols, update).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.upsert_by.as_ref() {
+            let ParenArgs(upsert_by) = &method.value;
+            let (vis, fn_name) = self.fn_info("upsert_by", method.vis.as_ref());
+            let update_type = &upsert_by.update_type;
+            let conflict_cols = &upsert_by.conflict_cols.0;
+
+            let Some(create_type) = upsert_by.create_type.as_ref().or(self
+                .args
+                .methods
+                .create
+                .as_ref()
+                .map(|m| &m.value))
+            else {
+                return Err(syn::Error::new(
+                    update_type.span(),
+                    "DbBmc: upsert_by requires a create_type if there is no create method.",
+                ));
+            };
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Create a row in the database, or update it if it conflicts on the configured columns, returning the resulting row.
+                    #vis async fn #fn_name<X>(executor: &mut X, data: #create_type, update: #update_type) -> std::result::Result<#model_type, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let conflict_cols = [#(sea_query::IntoIden::into_iden(#conflict_cols)),*];
+
+                        let res = bodega::upsert::<Self, _, _, _, _>(executor, data, &conflict_cols, update).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.delete.as_ref() {
+            let (vis, fn_name) = self.fn_info("delete", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Delete the given record from the store.
+                    #vis async fn #fn_name<X>(executor: &mut X, id: &#id_type) -> std::result::Result<(), #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        bodega::delete::<Self, _>(executor, id).await?;
+
+                        Ok(())
+                    }
+                }
+            });
+        }
+
+        if self.args.soft_delete.is_some() {
+            let (vis, fn_name) = self.fn_info("soft_delete", None);
+            let (restore_vis, restore_fn_name) = self.fn_info("restore", None);
+            let (list_deleted_vis, list_deleted_fn_name) = self.fn_info("list_deleted", None);
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Mark the given record as deleted, without removing it from the store.
+                    #vis async fn #fn_name<X>(executor: &mut X, id: &#id_type) -> std::result::Result<(), #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        bodega::soft_delete::<Self, _>(executor, id).await?;
+
+                        Ok(())
+                    }
+
+                    /// Restore the given soft-deleted record.
+                    #restore_vis async fn #restore_fn_name<X>(executor: &mut X, id: &#id_type) -> std::result::Result<(), #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        bodega::restore::<Self, _>(executor, id).await?;
+
+                        Ok(())
+                    }
+
+                    /// Fetch all soft-deleted rows from the store.
+                    #list_deleted_vis async fn #list_deleted_fn_name<X>(executor: &mut X) -> std::result::Result<Vec<#model_type>, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::list_deleted::<Self, _, _>(executor).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        // batch_delete_where is the same operation under a name that's harder
+        // to confuse with delete
+        let delete_wheres = [
+            ("delete_where", self.args.methods.delete_where.as_ref()),
+            (
+                "batch_delete_where",
+                self.args.methods.batch_delete_where.as_ref(),
+            ),
+        ];
+
+        for (basename, method) in delete_wheres {
+            let Some(method) = method else {
+                continue;
+            };
+
+            let filters = &method.value;
+            let (vis, fn_name) = self.fn_info(basename, method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Delete all rows matching the filters, returning the number of rows deleted.
+                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters) -> std::result::Result<u64, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::delete_where::<Self, _, _>(executor, filters).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.count.as_ref() {
+            let (vis, fn_name) = self.fn_info("count", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Delete the given record from the store.
+                    #vis async fn #fn_name<X>(executor: &mut X) -> std::result::Result<usize, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::count::<Self, _>(executor).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.count_filtered.as_ref() {
+            let filters = &method.value;
+            let (vis, fn_name) = self.fn_info("count_filtered", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Count the rows in the store that satisfy the filters.
+                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters) -> std::result::Result<usize, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::count_filtered::<Self, _, _>(executor, filters).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.exists.as_ref() {
+            let (vis, fn_name) = self.fn_info("exists", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Check if a record with the given id exists in the store.
+                    #vis async fn #fn_name<X>(executor: &mut X, id: &#id_type) -> std::result::Result<bool, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::exists::<Self, _>(executor, id).await?;
+
+                        Ok(res)
+                    }
+                }
+            });
+        }
+
+        if let Some(method) = self.args.methods.exists_where.as_ref() {
+            let filters = &method.value;
+            let (vis, fn_name) = self.fn_info("exists_where", method.vis.as_ref());
+
+            out.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Check if any records matching the filters exist in the store.
+                    #vis async fn #fn_name<X>(executor: &mut X, filters: &#filters) -> std::result::Result<bool, #error>
+                    where
+                        X: bodega::AsExecutor,
+                    {
+                        let res = bodega::exists_where::<Self, _, _>(executor, filters).await?;
 
                         Ok(res)
                     }
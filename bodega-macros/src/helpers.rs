@@ -1,6 +1,34 @@
-use syn::{GenericArgument, Path, PathArguments, PathSegment, Type};
+use darling::{ast::NestedMeta, FromMeta};
+use proc_macro2::TokenTree;
+use quote::ToTokens;
+use syn::{Attribute, Expr, GenericArgument, Meta, Path, PathArguments, PathSegment, Type};
 
 pub(crate) fn option_kind(ty: &Type) -> Option<&Type> {
+    wrapped_kind(
+        ty,
+        &["Option|", "std|option|Option|", "core|option|Option|"],
+    )
+}
+
+pub(crate) fn vec_kind(ty: &Type) -> Option<&Type> {
+    wrapped_kind(ty, &["Vec|", "std|vec|Vec|", "alloc|vec|Vec|"])
+}
+
+/// Returns `true` if the type is `u8`, for telling `Vec<u8>` (`bytea`) apart
+/// from other `Vec`s (arrays).
+pub(crate) fn is_u8(ty: &Type) -> bool {
+    matches!(ty, Type::Path(t) if t.qself.is_none() && t.path.is_ident("u8"))
+}
+
+/// Returns `true` if the type is `()` or an `Option`, for fields whose value
+/// is ignored, so that it's obvious no meaningful value is discarded.
+pub(crate) fn is_unit_or_option(ty: &Type) -> bool {
+    option_kind(ty).is_some() || matches!(ty, Type::Tuple(t) if t.elems.is_empty())
+}
+
+/// Extracts `T` from a type like `Wrapper<T>`, where the path of `Wrapper` is
+/// one of the given `|`-terminated paths.
+fn wrapped_kind<'a>(ty: &'a Type, wrapper_paths: &[&str]) -> Option<&'a Type> {
     // https://stackoverflow.com/questions/55271857/how-can-i-get-the-t-from-an-optiont-when-using-syn
     fn extract_type_path(ty: &syn::Type) -> Option<&Path> {
         match *ty {
@@ -9,20 +37,23 @@ pub(crate) fn option_kind(ty: &Type) -> Option<&Type> {
         }
     }
 
-    fn extract_option_segment(path: &Path) -> Option<&PathSegment> {
+    fn extract_wrapper_segment<'a>(
+        path: &'a Path,
+        wrapper_paths: &[&str],
+    ) -> Option<&'a PathSegment> {
         let idents_of_path = path.segments.iter().fold(String::new(), |mut acc, v| {
             acc.push_str(&v.ident.to_string());
             acc.push('|');
             acc
         });
-        ["Option|", "std|option|Option|", "core|option|Option|"]
-            .into_iter()
-            .find(|s| idents_of_path == *s)
+        wrapper_paths
+            .iter()
+            .find(|s| idents_of_path == **s)
             .and_then(|_| path.segments.last())
     }
 
     extract_type_path(ty)
-        .and_then(|path| extract_option_segment(path))
+        .and_then(|path| extract_wrapper_segment(path, wrapper_paths))
         .and_then(|path_seg| {
             let type_params = &path_seg.arguments;
             // It should have only one angle-bracketed param ("<String>"):
@@ -36,3 +67,130 @@ pub(crate) fn option_kind(ty: &Type) -> Option<&Type> {
             _ => None,
         })
 }
+
+/// Returns `true` if any of the given attrs is a `#[sqlx(...)]` attr
+/// containing the given flag (i.e. `json` in `#[sqlx(json)]`).
+pub(crate) fn has_sqlx_flag(attrs: &[Attribute], flag: &str) -> bool {
+    attrs.iter().any(|attr| match attr.meta {
+        Meta::List(ref list) if list.path.is_ident("sqlx") => {
+            list.tokens.clone().into_iter().any(|t| match t {
+                TokenTree::Ident(ref i) => i == flag,
+                _ => false,
+            })
+        }
+        _ => false,
+    })
+}
+
+/// Allows specifying args as `name = (a = b, c = d)` in addition to the usual
+/// `name(a = b, c = d)`.
+#[derive(Debug, Clone)]
+pub(crate) struct ParenArgs<T>(pub T);
+
+impl<T: FromMeta> FromMeta for ParenArgs<T> {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        T::from_list(items).map(Self)
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        let inner = match expr {
+            Expr::Tuple(tuple) => tuple.elems.to_token_stream(),
+            Expr::Paren(paren) => paren.expr.to_token_stream(),
+            Expr::Group(group) => return Self::from_expr(&group.expr),
+            _ => return Err(darling::Error::unexpected_expr_type(expr).with_span(expr)),
+        };
+
+        let items = NestedMeta::parse_meta_list(inner)?;
+
+        Self::from_list(&items).map_err(|e| e.with_span(expr))
+    }
+}
+
+/// The name of a SQL function taking no arguments, like `"clock_timestamp"`.
+///
+/// As this is emitted verbatim, it's restricted to valid identifiers.
+#[derive(Debug, Clone)]
+pub(crate) struct SqlFnName(String);
+
+impl SqlFnName {
+    /// The call of this function, like `clock_timestamp()`.
+    pub(crate) fn call(&self) -> String {
+        format!("{}()", self.0)
+    }
+}
+
+impl FromMeta for SqlFnName {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        let mut chars = value.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if valid {
+            Ok(Self(value.to_string()))
+        } else {
+            Err(darling::Error::custom(
+                "expected the name of a SQL function, like \"clock_timestamp\"",
+            ))
+        }
+    }
+}
+
+/// A minimal English pluralization of a snake_case name, like `box` to
+/// `boxes`, for deriving table names from model names.
+///
+/// Only the last word is pluralized, so `book_category` becomes
+/// `book_categories`. Names ending in `s`, like `settings` or `address`, are
+/// assumed to already be plural and are left as-is. Irregular plurals are not
+/// handled.
+pub(crate) fn pluralize(name: &str) -> String {
+    const SIBILANTS: [&str; 4] = ["ch", "sh", "x", "z"];
+
+    if name.ends_with('s') {
+        return name.to_string();
+    }
+
+    if SIBILANTS.iter().any(|suffix| name.ends_with(suffix)) {
+        return format!("{}es", name);
+    }
+
+    if let Some(stem) = name.strip_suffix('y') {
+        if stem
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_ascii_alphabetic() && !"aeiou".contains(c))
+        {
+            return format!("{}ies", stem);
+        }
+    }
+
+    format!("{}s", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pluralizing_names() {
+        let cases = [
+            ("book", "books"),
+            ("box", "boxes"),
+            ("watch", "watches"),
+            ("dish", "dishes"),
+            ("address", "address"),
+            ("bus", "bus"),
+            ("settings", "settings"),
+            ("user_stats", "user_stats"),
+            ("category", "categories"),
+            ("book_category", "book_categories"),
+            ("key", "keys"),
+            ("y", "ys"),
+        ];
+
+        for (name, expected) in cases {
+            assert_eq!(pluralize(name), expected, "pluralizing {name}");
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use darling::FromMeta;
+use proc_macro::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{spanned::Spanned, ItemStruct};
+
+use crate::newtype_id;
+
+#[derive(Debug, Copy, Clone, FromMeta)]
+pub(crate) struct I64Args {
+    // the db assigns the value, so a default is rarely meaningful
+    #[darling(default = default_true)]
+    skip_default: bool,
+
+    #[darling(default)]
+    skip_construction: bool,
+
+    #[darling(default)]
+    skip_display: bool,
+
+    #[darling(default)]
+    skip_refs: bool,
+
+    #[darling(default)]
+    skip_store: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+pub fn i64_id_impl(args: I64Args, mut input: ItemStruct) -> syn::Result<TokenStream> {
+    if !args.skip_store {
+        newtype_id::store_attrs(&mut input);
+    }
+
+    match &input.fields {
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            let mut out = input.to_token_stream();
+
+            let ident = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+            let inner = quote!(i64);
+
+            if !args.skip_default {
+                out.extend(newtype_id::default(
+                    ident,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                    quote!(0),
+                )?);
+            }
+
+            if !args.skip_construction {
+                out.extend(newtype_id::construction(
+                    ident,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                    &inner,
+                )?);
+            }
+
+            if !args.skip_refs {
+                out.extend(newtype_id::refs(
+                    ident,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                    &inner,
+                )?);
+            }
+
+            if !args.skip_display {
+                out.extend(newtype_id::display(
+                    ident,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                )?);
+            }
+
+            if !args.skip_store {
+                out.extend(newtype_id::query_impls(
+                    ident,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                )?);
+            }
+
+            Ok(out.into())
+        }
+        _ => Err(syn::Error::new(
+            input.span(),
+            "I64Id: Only newtypes in form Foo(i64) are supported.",
+        )),
+    }
+}
@@ -4,6 +4,8 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{spanned::Spanned, DeriveInput, Ident, Path, Type};
 
+use crate::helpers::{is_u8, is_unit_or_option, option_kind, vec_kind, SqlFnName};
+
 #[derive(Debug, Clone, FromDeriveInput)]
 #[darling(attributes(insert), supports(struct_named))]
 pub(crate) struct InsertArgs {
@@ -15,12 +17,23 @@ pub(crate) struct InsertArgs {
 #[darling(attributes(insert))]
 pub(crate) struct InsertField {
     ident: Option<Ident>,
-    #[allow(unused)]
     ty: Type,
     #[darling(default)]
     iden: Option<Path>,
     #[darling(default)]
+    rename: Option<String>,
+    #[darling(default)]
     cust_opt: bool,
+    #[darling(default)]
+    pg_cast: Option<String>,
+    #[darling(default)]
+    default_fn: Option<Path>,
+    #[darling(default)]
+    timestamp_fn: Option<SqlFnName>,
+    #[darling(default)]
+    seq: Option<String>,
+    #[darling(default)]
+    skip: bool,
 }
 
 pub fn insert_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
@@ -52,9 +65,111 @@ impl<'a> InsertInfo<'a> {
         let mut iden_fields = Vec::default();
         let mut inserts = Vec::default();
 
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.iden.is_some() && f.rename.is_some())
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Insert: iden and rename cannot be used on the same field.",
+            ));
+        }
+
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.cust_opt && f.default_fn.is_some())
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Insert: cust_opt and default_fn cannot be used on the same field.",
+            ));
+        }
+
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.timestamp_fn.is_some() && (f.cust_opt || f.default_fn.is_some()))
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Insert: timestamp_fn cannot be combined with cust_opt or default_fn.",
+            ));
+        }
+
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields.into_iter().find(|f| {
+                f.seq.is_some()
+                    && (f.cust_opt || f.default_fn.is_some() || f.timestamp_fn.is_some())
+            })
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Insert: seq cannot be combined with cust_opt, default_fn, or timestamp_fn.",
+            ));
+        }
+
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields.into_iter().find(|f| {
+                f.skip
+                    && (f.cust_opt
+                        || f.pg_cast.is_some()
+                        || f.default_fn.is_some()
+                        || f.timestamp_fn.is_some()
+                        || f.seq.is_some())
+            })
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Insert: skip cannot be combined with any other insert attribute.",
+            ));
+        }
+
+        // a skipped field still has to be provided when constructing the
+        // struct, so make sure it's obvious that its value is never used.
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.skip && !is_unit_or_option(&f.ty))
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Insert: fields marked #[insert(skip)] must be an Option or (), as their value is never inserted.",
+            ));
+        }
+
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.timestamp_fn.is_some() && !is_unit_or_option(&f.ty))
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Insert: fields marked #[insert(timestamp_fn)] must be an Option or (), as their value is ignored.",
+            ));
+        }
+
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.seq.is_some() && !is_unit_or_option(&f.ty))
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Insert: fields marked #[insert(seq)] must be an Option or (), as their value is ignored.",
+            ));
+        }
+
         self.args.data.as_ref().map_struct_fields(|field| {
-            if let Some(iden) = field.iden.clone() {
-                iden_fields.push(iden);
+            if field.skip {
+                return;
+            }
+
+            if let Some(ref rename) = field.rename {
+                iden_fields.push(quote! { sea_query::Alias::new(#rename) });
+            } else if let Some(ref iden) = field.iden {
+                iden_fields.push(quote! { #iden });
             } else {
                 let ident = Ident::new(
                     &field
@@ -69,14 +184,52 @@ impl<'a> InsertInfo<'a> {
                     ident: ident.clone(),
                     arguments: syn::PathArguments::None,
                 });
-                iden_fields.push(working);
+                iden_fields.push(quote! { #working });
             }
             let ident = field.ident.as_ref().expect("Only named structs supported");
 
-            if field.cust_opt {
-                inserts.push(quote! { bodega::CustomOption(self.#ident).into() });
+            let value = if let Some(ref timestamp_fn) = field.timestamp_fn {
+                let call = timestamp_fn.call();
+                quote! {
+                    {
+                        let _ = self.#ident;
+                        sea_query::Expr::cust(#call)
+                    }
+                }
+            } else if let Some(ref seq) = field.seq {
+                quote! {
+                    {
+                        let _ = self.#ident;
+                        sea_query::Func::cust(sea_query::Alias::new("nextval"))
+                            .arg(#seq)
+                            .into()
+                    }
+                }
+            } else if field.cust_opt
+                && option_kind(&field.ty)
+                    .and_then(vec_kind)
+                    .is_some_and(|elem| !is_u8(elem))
+            {
+                // Vec<u8> is bytea rather than an array, so it's left to
+                // CustomOption
+                quote! { bodega::CustomVecOption(self.#ident).into() }
+            } else if field.cust_opt {
+                quote! { bodega::CustomOption(self.#ident).into() }
+            } else if let Some(ref default_fn) = field.default_fn {
+                quote! { self.#ident.unwrap_or_else(#default_fn).into() }
+            } else {
+                quote! { self.#ident.into() }
+            };
+
+            if let Some(ref pg_cast) = field.pg_cast {
+                inserts.push(quote! {
+                    {
+                        let val: sea_query::SimpleExpr = #value;
+                        val.cast_as(sea_query::Alias::new(#pg_cast))
+                    }
+                });
             } else {
-                inserts.push(quote! { self.#ident.into() });
+                inserts.push(value);
             }
         });
 
@@ -1,11 +1,15 @@
+// the darling parse results are matched explicitly rather than with `?`
+#![allow(clippy::question_mark)]
 use darling::{ast::NestedMeta, FromMeta};
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
 mod db_bmc;
 mod helpers;
+mod i64_id;
 mod insert;
 mod json_value;
+mod newtype_id;
 mod select;
 mod store_enum;
 mod update;
@@ -58,6 +62,23 @@ pub fn uuid_id(attr_args: TokenStream, item: TokenStream) -> TokenStream {
     uuid_id::uuid_id_impl(args, input).unwrap_or_else(|e| e.to_compile_error().into())
 }
 
+#[proc_macro_attribute]
+pub fn i64_id(attr_args: TokenStream, item: TokenStream) -> TokenStream {
+    let attr_args = match NestedMeta::parse_meta_list(attr_args.into()) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(darling::Error::from(e).write_errors()),
+    };
+
+    let args = match i64_id::I64Args::from_list(&attr_args) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+
+    let input = syn::parse_macro_input!(item as syn::ItemStruct);
+
+    i64_id::i64_id_impl(args, input).unwrap_or_else(|e| e.to_compile_error().into())
+}
+
 #[proc_macro_attribute]
 pub fn store_enum(attr_args: TokenStream, item: TokenStream) -> TokenStream {
     let attr_args = match NestedMeta::parse_meta_list(attr_args.into()) {
@@ -0,0 +1,123 @@
+//! Generators for the impls shared by the `uuid_id` and `i64_id` newtypes,
+//! where `inner` is the wrapped type (i.e. `uuid::Uuid`).
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Ident, ImplGenerics, ItemStruct, TypeGenerics, WhereClause};
+
+pub(crate) fn store_attrs(input: &mut ItemStruct) {
+    input.attrs.push(parse_quote!(#[derive(sqlx::Type)]));
+    input.attrs.push(parse_quote!(#[sqlx(transparent)]));
+}
+
+pub(crate) fn default(
+    ident: &Ident,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+    value: TokenStream,
+) -> syn::Result<TokenStream> {
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics Default for #ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self(#value)
+            }
+        }
+    })
+}
+
+pub(crate) fn construction(
+    ident: &Ident,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+    inner: &TokenStream,
+) -> syn::Result<TokenStream> {
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            pub fn new(id: #inner) -> Self {
+                Self(id)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics From<#inner> for #ident #ty_generics #where_clause {
+            fn from(value: #inner) -> Self {
+                Self::new(value)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics From<&#inner> for #ident #ty_generics #where_clause {
+            fn from(value: &#inner) -> Self {
+                Self::new(*value)
+            }
+        }
+    })
+}
+
+pub(crate) fn refs(
+    ident: &Ident,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+    inner: &TokenStream,
+) -> syn::Result<TokenStream> {
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics std::ops::Deref for #ident #ty_generics #where_clause {
+            type Target = #inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics AsRef<#inner> for #ident #ty_generics #where_clause {
+            fn as_ref(&self) -> &#inner {
+                &self.0
+            }
+        }
+    })
+}
+
+pub(crate) fn display(
+    ident: &Ident,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+) -> syn::Result<TokenStream> {
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics std::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    })
+}
+
+pub(crate) fn query_impls(
+    ident: &Ident,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+) -> syn::Result<TokenStream> {
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics From<#ident #ty_generics> for sea_query::Value #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                value.0.into()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics From<&#ident #ty_generics> for sea_query::Value #where_clause {
+            fn from(value: &#ident #ty_generics) -> Self {
+                value.0.into()
+            }
+        }
+    })
+}
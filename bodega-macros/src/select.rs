@@ -3,21 +3,40 @@ use heck::ToUpperCamelCase;
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
-use syn::{spanned::Spanned, DataStruct, DeriveInput, Fields, Type};
+use syn::{ext::IdentExt, spanned::Spanned, Attribute, DeriveInput, Fields, Path, Type, TypePath};
+
+use crate::helpers::has_sqlx_flag;
 
 #[derive(FromDeriveInput, Clone)]
 #[darling(attributes(select), supports(struct_named))]
 pub(crate) struct SelectArgs {
     data: ast::Data<util::Ignored, SelectField>,
+    #[darling(default)]
+    columns: Option<String>,
 }
 
 #[derive(Debug, Clone, FromField)]
-#[darling(attributes(select))]
+#[darling(attributes(select), forward_attrs(sqlx))]
 pub(crate) struct SelectField {
     ident: Option<Ident>,
     ty: Type,
+    attrs: Vec<Attribute>,
     #[darling(default)]
     cursor: bool,
+    #[darling(default)]
+    cursor_type: Option<Path>,
+    #[darling(default)]
+    json: bool,
+    #[darling(default)]
+    flatten: bool,
+    #[darling(default)]
+    skip: bool,
+}
+
+#[derive(Debug, Clone)]
+enum SelectCol {
+    Iden(Ident),
+    Flatten(Type),
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +44,8 @@ struct CursorAttr {
     ident: Ident,
     cursor_iden: Ident,
     ty: Type,
+    // whether the field must be converted into the explicit cursor type
+    convert: bool,
 }
 
 pub fn select_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
@@ -42,8 +63,9 @@ pub struct ModelType<'a> {
     input: &'a DeriveInput,
     name: Ident,
     iden_name: Ident,
-    iden_fields: Vec<Ident>,
-    cursor: Option<CursorAttr>,
+    cols: Vec<SelectCol>,
+    columns: Option<Vec<String>>,
+    cursors: Vec<CursorAttr>,
 }
 
 impl ModelType<'_> {
@@ -51,7 +73,38 @@ impl ModelType<'_> {
         let name = &self.name;
         let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
         let iden_name = &self.iden_name;
-        let iden_fields = &self.iden_fields;
+
+        let select_cols = if let Some(ref columns) = self.columns {
+            quote! {
+                vec![
+                    #(sea_query::Alias::new(#columns).into_iden()),*
+                ]
+            }
+        } else if self.cols.iter().all(|c| matches!(c, SelectCol::Iden(_))) {
+            let iden_fields = self.cols.iter().filter_map(|c| match c {
+                SelectCol::Iden(i) => Some(i),
+                SelectCol::Flatten(_) => None,
+            });
+
+            quote! {
+                vec![
+                    #(#iden_name::#iden_fields.into_iden()),*
+                ]
+            }
+        } else {
+            let parts = self.cols.iter().map(|c| match c {
+                SelectCol::Iden(i) => quote! { cols.push(#iden_name::#i.into_iden()); },
+                SelectCol::Flatten(ty) => {
+                    quote! { cols.extend(<#ty as bodega::Select>::select_cols()); }
+                }
+            });
+
+            quote! {
+                let mut cols = Vec::new();
+                #(#parts)*
+                cols
+            }
+        };
 
         let mut out = quote! {
             #[automatically_derived]
@@ -59,34 +112,100 @@ impl ModelType<'_> {
                 fn select_cols() -> Vec<sea_query::DynIden> {
                     use sea_query::IntoIden;
 
-                    vec![
-                        #(#iden_name::#iden_fields.into_iden()),*
-                    ]
+                    #select_cols
                 }
             }
         };
 
-        if let Some(ref cursor) = self.cursor {
-            let ident = &cursor.ident;
-            let cursor_iden = &cursor.cursor_iden;
-            let ty = &cursor.ty;
+        let cursor_column = |cursor: &CursorAttr| {
+            if let Some(ref columns) = self.columns {
+                let col_name = cursor.ident.unraw().to_string();
+
+                if !columns.contains(&col_name) {
+                    return Err(syn::Error::new(
+                        cursor.ident.span(),
+                        format!(
+                            "Select: cursor field `{col_name}` is not one of the given columns."
+                        ),
+                    ));
+                }
+
+                Ok(quote! { sea_query::Alias::new(#col_name).into_iden() })
+            } else {
+                let cursor_iden = &cursor.cursor_iden;
+                Ok(quote! { #iden_name::#cursor_iden.into_iden() })
+            }
+        };
+
+        match self.cursors.as_slice() {
+            [] => {}
+            [cursor] => {
+                let ident = &cursor.ident;
+                let ty = &cursor.ty;
+
+                let cursor_value = if cursor.convert {
+                    quote! { self.#ident.clone().into() }
+                } else {
+                    quote! { self.#ident.clone() }
+                };
+
+                let cursor_column = cursor_column(cursor)?;
+
+                out.extend(quote! {
+                    #[automatically_derived]
+                    impl #impl_generics bodega::Cursored for #name #ty_generics #where_clause {
+                        type CursorType = #ty;
+
+                        fn cursor_value(&self) -> Self::CursorType {
+                            #cursor_value
+                        }
 
-            out.extend(quote! {
-                #[automatically_derived]
-                impl #impl_generics bodega::Cursored for #name #ty_generics #where_clause {
-                    type CursorType = #ty;
+                        fn cursor_column() -> sea_query::DynIden {
+                            use sea_query::IntoIden;
 
-                    fn cursor_value(&self) -> Self::CursorType {
-                        self.#ident.clone()
+                            #cursor_column
+                        }
                     }
+                });
+            }
+            [first, second] => {
+                let (first_ident, second_ident) = (&first.ident, &second.ident);
+                let (first_ty, second_ty) = (&first.ty, &second.ty);
+                let first_column = cursor_column(first)?;
+                let second_column = cursor_column(second)?;
+
+                out.extend(quote! {
+                    #[automatically_derived]
+                    impl #impl_generics bodega::Cursored for #name #ty_generics #where_clause {
+                        type CursorType = bodega::CompoundCursor<#first_ty, #second_ty>;
+
+                        fn cursor_value(&self) -> Self::CursorType {
+                            bodega::CompoundCursor(
+                                self.#first_ident.clone(),
+                                self.#second_ident.clone(),
+                            )
+                        }
+
+                        fn cursor_column() -> sea_query::DynIden {
+                            use sea_query::IntoIden;
+
+                            #first_column
+                        }
 
-                    fn cursor_column() -> sea_query::DynIden {
-                        use sea_query::IntoIden;
+                        fn cursor_columns() -> Vec<sea_query::DynIden> {
+                            use sea_query::IntoIden;
 
-                        #iden_name::#cursor_iden.into_iden()
+                            vec![#first_column, #second_column]
+                        }
                     }
-                }
-            });
+                });
+            }
+            [.., extra] => {
+                return Err(syn::Error::new(
+                    extra.ident.span(),
+                    "Select: at most two fields can be marked #[select(cursor)].",
+                ));
+            }
         }
 
         Ok(out)
@@ -99,7 +218,12 @@ impl<'a> TryFrom<&'a DeriveInput> for ModelType<'a> {
     fn try_from(value: &'a DeriveInput) -> Result<Self, Self::Error> {
         match value.data {
             syn::Data::Struct(ref data) => {
-                let iden_fields = extract_field_iden_idents(data)?;
+                if !matches!(data.fields, Fields::Named(_)) {
+                    return Err(syn::Error::new(
+                        data.fields.span(),
+                        "Select: Structs with unnamed fields are not supported.",
+                    ));
+                }
 
                 let iden_name = Ident::new(&format!("{}Iden", value.ident), Span::call_site());
 
@@ -108,31 +232,130 @@ impl<'a> TryFrom<&'a DeriveInput> for ModelType<'a> {
                     Err(e) => return Err(e.into()),
                 };
 
-                let mut cursor = None;
+                let columns = match args.columns {
+                    Some(ref raw) => {
+                        let columns: Vec<String> = raw
+                            .split(',')
+                            .map(|c| c.trim())
+                            .filter(|c| !c.is_empty())
+                            .map(String::from)
+                            .collect();
 
-                args.data.map_struct_fields(|field| {
-                    if field.cursor {
-                        let ident = field
-                            .ident
-                            .expect("Should have not been possible to have an unnamed field");
+                        if columns.is_empty() {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                "Select: columns must contain at least one column name.",
+                            ));
+                        }
+
+                        Some(columns)
+                    }
+                    None => None,
+                };
+
+                let mut cursors = Vec::new();
+                let mut cols = Vec::new();
+
+                let fields = args
+                    .data
+                    .take_struct()
+                    .expect("Should have not been possible to have a non-struct");
+
+                for field in fields {
+                    // we can't add the attr ourselves, as derives cannot modify
+                    // the item, but we can at least make the error obvious.
+                    if field.json && !has_sqlx_flag(&field.attrs, "json") {
+                        return Err(syn::Error::new(
+                            field.ty.span(),
+                            "Select: fields marked #[select(json)] must also be marked #[sqlx(json)].",
+                        ));
+                    }
+
+                    if field.cursor_type.is_some() && !field.cursor {
+                        return Err(syn::Error::new(
+                            field.ty.span(),
+                            "Select: cursor_type can only be used on the cursor field, as #[select(cursor, cursor_type = ...)].",
+                        ));
+                    }
+
+                    if field.skip {
+                        if !has_sqlx_flag(&field.attrs, "skip") {
+                            return Err(syn::Error::new(
+                                field.ty.span(),
+                                "Select: fields marked #[select(skip)] must also be marked #[sqlx(skip)].",
+                            ));
+                        }
+
+                        if field.cursor || field.json || field.flatten {
+                            return Err(syn::Error::new(
+                                field.ty.span(),
+                                "Select: fields marked #[select(skip)] cannot also be a cursor, json, or flatten.",
+                            ));
+                        }
 
+                        continue;
+                    }
+
+                    if field.flatten {
+                        if !has_sqlx_flag(&field.attrs, "flatten") {
+                            return Err(syn::Error::new(
+                                field.ty.span(),
+                                "Select: fields marked #[select(flatten)] must also be marked #[sqlx(flatten)].",
+                            ));
+                        }
+
+                        if field.cursor || field.json {
+                            return Err(syn::Error::new(
+                                field.ty.span(),
+                                "Select: fields marked #[select(flatten)] cannot also be a cursor or json.",
+                            ));
+                        }
+
+                        cols.push(SelectCol::Flatten(field.ty));
+                        continue;
+                    }
+
+                    let ident = field
+                        .ident
+                        .expect("Should have not been possible to have an unnamed field");
+
+                    let field_iden =
+                        Ident::new(&ident.to_string().to_upper_camel_case(), Span::call_site());
+
+                    cols.push(SelectCol::Iden(field_iden));
+
+                    if field.cursor {
                         let cursor_iden =
                             Ident::new(&ident.to_string().to_upper_camel_case(), ident.span());
 
-                        cursor = Some(CursorAttr {
+                        cursors.push(CursorAttr {
                             ident,
                             cursor_iden,
-                            ty: field.ty,
-                        })
+                            convert: field.cursor_type.is_some(),
+                            ty: field
+                                .cursor_type
+                                .map(|path| Type::Path(TypePath { qself: None, path }))
+                                .unwrap_or(field.ty),
+                        });
                     }
-                });
+                }
+
+                if cursors.len() > 1 {
+                    if let Some(cursor) = cursors.iter().find(|c| c.convert) {
+                        return Err(syn::Error::new(
+                            cursor.ident.span(),
+                            "Select: cursor_type cannot be used with a compound cursor.",
+                        ));
+                    }
+                }
 
                 Ok(Self {
                     input: value,
                     name: value.ident.clone(),
                     iden_name,
-                    iden_fields,
-                    cursor,
+                    cols,
+                    columns,
+                    cursors,
                 })
             }
             _ => Err(syn::Error::new(
@@ -142,21 +365,3 @@ impl<'a> TryFrom<&'a DeriveInput> for ModelType<'a> {
         }
     }
 }
-
-fn extract_field_iden_idents(data: &DataStruct) -> syn::Result<Vec<Ident>> {
-    match data.fields {
-        Fields::Named(ref fields) => Ok(fields
-            .named
-            .iter()
-            .filter_map(|f| {
-                f.ident
-                    .as_ref()
-                    .map(|i| Ident::new(&i.to_string().to_upper_camel_case(), Span::call_site()))
-            })
-            .collect()),
-        _ => Err(syn::Error::new(
-            data.fields.span(),
-            "Select: Structs with unnamed fields are not supported.",
-        )),
-    }
-}
@@ -4,7 +4,7 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{spanned::Spanned, DeriveInput, Ident, Path, Type};
 
-use crate::helpers::option_kind;
+use crate::helpers::{is_unit_or_option, option_kind, SqlFnName};
 
 #[derive(Debug, Clone, FromDeriveInput)]
 #[darling(attributes(update), supports(struct_named))]
@@ -20,6 +20,21 @@ pub(crate) struct UpdateField {
     ty: Type,
     #[darling(default)]
     iden: Option<Path>,
+    #[darling(default)]
+    rename: Option<String>,
+    #[darling(default)]
+    null_if_none: bool,
+    #[darling(default)]
+    force: bool,
+    #[darling(default)]
+    timestamp_fn: Option<SqlFnName>,
+}
+
+impl UpdateField {
+    /// `force` is an alias for `null_if_none`.
+    fn null_if_none(&self) -> bool {
+        self.null_if_none || self.force
+    }
 }
 
 pub fn update_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
@@ -48,12 +63,58 @@ impl<'a> UpdateInfo<'a> {
         let name = &self.input.ident;
         let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
 
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.iden.is_some() && f.rename.is_some())
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Update: iden and rename cannot be used on the same field.",
+            ));
+        }
+
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.null_if_none() && option_kind(&f.ty).is_none())
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Update: fields marked #[update(null_if_none)] or #[update(force)] must be an Option.",
+            ));
+        }
+
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.null_if_none() && f.timestamp_fn.is_some())
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Update: timestamp_fn cannot be combined with null_if_none.",
+            ));
+        }
+
+        if let Some(field) = self.args.data.as_ref().take_struct().and_then(|fields| {
+            fields
+                .into_iter()
+                .find(|f| f.timestamp_fn.is_some() && !is_unit_or_option(&f.ty))
+        }) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "Update: fields marked #[update(timestamp_fn)] must be an Option or (), as their value is ignored.",
+            ));
+        }
+
         let mut body = quote! {};
         self.args.data.as_ref().map_struct_fields(|field| {
             let is_option = option_kind(&field.ty).is_some();
 
-            let iden = if let Some(iden) = field.iden.clone() {
-                iden
+            let iden = if let Some(ref rename) = field.rename {
+                quote! { sea_query::Alias::new(#rename) }
+            } else if let Some(ref iden) = field.iden {
+                quote! { #iden }
             } else {
                 let ident = Ident::new(
                     &field
@@ -68,11 +129,21 @@ impl<'a> UpdateInfo<'a> {
                     ident: ident.clone(),
                     arguments: syn::PathArguments::None,
                 });
-                working
+                quote! { #working }
             };
 
             let ident = &field.ident;
-            if is_option {
+            if let Some(ref timestamp_fn) = field.timestamp_fn {
+                let call = timestamp_fn.call();
+                body.extend(quote! {
+                    let _ = self.#ident;
+                    out.push((#iden.into_iden(), sea_query::Expr::cust(#call)));
+                });
+            } else if field.null_if_none() {
+                body.extend(quote! {
+                    out.push((#iden.into_iden(), bodega::CustomOption(self.#ident).into()));
+                });
+            } else if is_option {
                 body.extend(quote! {
                     if let Some(val) = self.#ident {
                         out.push((#iden.into_iden(), val.into()));
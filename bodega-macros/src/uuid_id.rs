@@ -1,9 +1,10 @@
 use darling::FromMeta;
+use heck::ToSnakeCase;
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{
-    parse_quote, spanned::Spanned, Ident, ImplGenerics, ItemStruct, TypeGenerics, WhereClause,
-};
+use syn::{spanned::Spanned, Ident, ImplGenerics, ItemStruct, TypeGenerics, WhereClause};
+
+use crate::newtype_id;
 
 #[derive(Debug, Default, Copy, Clone, FromMeta)]
 pub(crate) struct UuidArgs {
@@ -21,12 +22,17 @@ pub(crate) struct UuidArgs {
 
     #[darling(default)]
     skip_store: bool,
+
+    #[darling(default)]
+    iden: bool,
+
+    #[darling(default)]
+    ord_by_time: bool,
 }
 
 pub fn uuid_id_impl(args: UuidArgs, mut input: ItemStruct) -> syn::Result<TokenStream> {
     if !args.skip_store {
-        input.attrs.push(parse_quote!(#[derive(sqlx::Type)]));
-        input.attrs.push(parse_quote!(#[sqlx(transparent)]));
+        newtype_id::store_attrs(&mut input);
     }
 
     match &input.fields {
@@ -35,30 +41,62 @@ pub fn uuid_id_impl(args: UuidArgs, mut input: ItemStruct) -> syn::Result<TokenS
 
             let ident = &input.ident;
             let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+            let inner = quote!(uuid::Uuid);
 
             if !args.skip_default {
-                out.extend(default(ident, &impl_generics, &ty_generics, where_clause)?);
+                out.extend(newtype_id::default(
+                    ident,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                    quote!(uuid::Uuid::now_v7()),
+                )?);
             }
 
             if !args.skip_construction {
-                out.extend(construction(
+                out.extend(newtype_id::construction(
                     ident,
                     &impl_generics,
                     &ty_generics,
                     where_clause,
+                    &inner,
                 )?);
             }
 
             if !args.skip_refs {
-                out.extend(refs(ident, &impl_generics, &ty_generics, where_clause)?);
+                out.extend(newtype_id::refs(
+                    ident,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                    &inner,
+                )?);
             }
 
             if !args.skip_display {
-                out.extend(display(ident, &impl_generics, &ty_generics, where_clause)?);
+                out.extend(newtype_id::display(
+                    ident,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                )?);
             }
 
             if !args.skip_store {
-                out.extend(query_impls(
+                out.extend(newtype_id::query_impls(
+                    ident,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                )?);
+            }
+
+            if args.iden {
+                out.extend(iden(ident, &impl_generics, &ty_generics, where_clause)?);
+            }
+
+            if args.ord_by_time {
+                out.extend(ord_by_time(
                     ident,
                     &impl_generics,
                     &ty_generics,
@@ -75,94 +113,25 @@ pub fn uuid_id_impl(args: UuidArgs, mut input: ItemStruct) -> syn::Result<TokenS
     }
 }
 
-fn default(
+fn iden(
     ident: &Ident,
     impl_generics: &ImplGenerics,
     ty_generics: &TypeGenerics,
     where_clause: Option<&WhereClause>,
 ) -> syn::Result<proc_macro2::TokenStream> {
-    Ok(quote! {
-        #[automatically_derived]
-        impl #impl_generics Default for #ident #ty_generics #where_clause {
-            fn default() -> Self {
-                Self(uuid::Uuid::now_v7())
-            }
-        }
-    })
-}
+    let name = ident.to_string().to_snake_case();
 
-fn construction(
-    ident: &Ident,
-    impl_generics: &ImplGenerics,
-    ty_generics: &TypeGenerics,
-    where_clause: Option<&WhereClause>,
-) -> syn::Result<proc_macro2::TokenStream> {
-    Ok(quote! {
-        #[automatically_derived]
-        impl #impl_generics #ident #ty_generics #where_clause {
-            pub fn new(uuid: uuid::Uuid) -> Self {
-                Self(uuid)
-            }
-        }
-
-        #[automatically_derived]
-        impl #impl_generics From<uuid::Uuid> for #ident #ty_generics #where_clause {
-            fn from(value: uuid::Uuid) -> Self {
-                Self::new(value)
-            }
-        }
-
-        #[automatically_derived]
-        impl #impl_generics From<&uuid::Uuid> for #ident #ty_generics #where_clause {
-            fn from(value: &uuid::Uuid) -> Self {
-                Self::new(*value)
-            }
-        }
-    })
-}
-
-fn refs(
-    ident: &Ident,
-    impl_generics: &ImplGenerics,
-    ty_generics: &TypeGenerics,
-    where_clause: Option<&WhereClause>,
-) -> syn::Result<proc_macro2::TokenStream> {
-    Ok(quote! {
-        #[automatically_derived]
-        impl #impl_generics std::ops::Deref for #ident #ty_generics #where_clause {
-            type Target = uuid::Uuid;
-
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
-
-        #[automatically_derived]
-        impl #impl_generics AsRef<uuid::Uuid> for #ident #ty_generics #where_clause {
-            fn as_ref(&self) -> &uuid::Uuid {
-                &self.0
-            }
-        }
-    })
-}
-
-fn display(
-    ident: &Ident,
-    impl_generics: &ImplGenerics,
-    ty_generics: &TypeGenerics,
-    where_clause: Option<&WhereClause>,
-) -> syn::Result<proc_macro2::TokenStream> {
     Ok(quote! {
         #[automatically_derived]
-        impl #impl_generics std::fmt::Display for #ident #ty_generics #where_clause {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                std::fmt::Display::fmt(&self.0, f)
+        impl #impl_generics sea_query::Iden for #ident #ty_generics #where_clause {
+            fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+                s.write_str(#name).expect("failed to write iden");
             }
         }
     })
 }
 
-fn query_impls(
+fn ord_by_time(
     ident: &Ident,
     impl_generics: &ImplGenerics,
     ty_generics: &TypeGenerics,
@@ -170,16 +139,22 @@ fn query_impls(
 ) -> syn::Result<proc_macro2::TokenStream> {
     Ok(quote! {
         #[automatically_derived]
-        impl #impl_generics From<#ident #ty_generics> for sea_query::Value #where_clause {
-            fn from(value: #ident #ty_generics) -> Self {
-                value.0.into()
+        impl #impl_generics PartialOrd for #ident #ty_generics #where_clause {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
             }
         }
 
         #[automatically_derived]
-        impl #impl_generics From<&#ident #ty_generics> for sea_query::Value #where_clause {
-            fn from(value: &#ident #ty_generics) -> Self {
-                value.0.into()
+        impl #impl_generics Ord for #ident #ty_generics #where_clause {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                // uuids without a timestamp sort first, and the bytes break
+                // ties between uuids with the same timestamp
+                let ts = |id: &uuid::Uuid| id.get_timestamp().map(|t| t.to_unix());
+
+                ts(&self.0)
+                    .cmp(&ts(&other.0))
+                    .then_with(|| self.0.cmp(&other.0))
             }
         }
     })
@@ -1,14 +1,26 @@
-use std::fmt::Display;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    future::Future,
+    marker::PhantomData,
+};
 
 use sea_query::{
-    DynIden, Expr, PostgresQueryBuilder, Query, SelectStatement, SimpleExpr, TableRef,
+    Condition, ConditionalStatement, DynIden, Expr, InsertStatement, IntoCondition, IntoIden,
+    LockType, NullOrdering, OnConflict, PostgresQueryBuilder, Query, SelectStatement, SimpleExpr,
+    TableRef,
 };
 use sea_query_binder::SqlxBinder;
 use serde::{Deserialize, Serialize};
 use snafu::{ResultExt, Snafu};
-use sqlx::{postgres::PgRow, prelude::FromRow, Postgres};
+use sqlx::{postgres::PgRow, prelude::FromRow, Postgres, Row};
 
-use crate::{AsExecutor, Cursored, CursoredFilter, Error, Paginated, Result};
+use crate::{
+    error::ExecuteRawSnafu,
+    expr::{eq_any, SelectStatementExt},
+    AsExecutor, Cursored, CursoredFilter, Error, OffsetPaginated, Paginated, Result,
+    StablePaginated,
+};
 
 /// Indicates that this type supports selection from the db by defining the
 /// columns that should be fetched.
@@ -39,11 +51,227 @@ pub trait Update {
     fn update_values(self) -> Vec<(DynIden, SimpleExpr)>;
 }
 
+/// A no-op update, setting no columns.
+///
+/// Passing this to [update] results in [`Error::EmptyUpdate`], so see [touch]
+/// for updating a row without changing any values.
+impl Update for () {
+    fn update_values(self) -> Vec<(DynIden, SimpleExpr)> {
+        Vec::new()
+    }
+}
+
+/// Accumulates (column, value) pairs for updates that can only be determined
+/// at runtime.
+///
+/// See [update_conditional].
+#[derive(Debug, Clone, Default)]
+pub struct UpdateBuilder {
+    values: Vec<(DynIden, SimpleExpr)>,
+}
+
+impl UpdateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the given column to the given value.
+    pub fn set<C, V>(&mut self, col: C, value: V) -> &mut Self
+    where
+        C: IntoIden,
+        V: Into<SimpleExpr>,
+    {
+        self.values.push((col.into_iden(), value.into()));
+        self
+    }
+
+    /// Returns `true` if no columns have been set.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Update for UpdateBuilder {
+    fn update_values(self) -> Vec<(DynIden, SimpleExpr)> {
+        self.values
+    }
+}
+
+/// Options for [list_with_options].
+///
+/// The default options order by the id column according to
+/// [`DbBmc::DEFAULT_ORDER`], without a limit, like [list].
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    order_by: Option<(DynIden, sea_query::Order)>,
+    limit: Option<u64>,
+}
+
+impl ListOptions {
+    pub fn builder() -> ListOptionsBuilder {
+        ListOptionsBuilder::new()
+    }
+}
+
+/// Builds [`ListOptions`], like
+/// `ListOptionsBuilder::new().order_by(col, Order::Asc).limit(100).build()`.
+#[derive(Debug, Clone, Default)]
+pub struct ListOptionsBuilder {
+    options: ListOptions,
+}
+
+impl ListOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Order by the given column instead of the id.
+    pub fn order_by<C>(mut self, col: C, order: sea_query::Order) -> Self
+    where
+        C: IntoIden,
+    {
+        self.options.order_by = Some((col.into_iden(), order));
+        self
+    }
+
+    /// Return at most `limit` rows.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.options.limit = Some(limit);
+        self
+    }
+
+    pub fn build(self) -> ListOptions {
+        self.options
+    }
+}
+
+/// A column to order rows by, and in which direction, for [list_ordered].
+#[derive(Debug, Clone)]
+pub struct Ordering {
+    col: DynIden,
+    order: sea_query::Order,
+}
+
+impl Ordering {
+    pub fn new<C>(col: C, order: sea_query::Order) -> Self
+    where
+        C: IntoIden,
+    {
+        Self {
+            col: col.into_iden(),
+            order,
+        }
+    }
+
+    /// Order by the given column, ascending.
+    pub fn asc<C>(col: C) -> Self
+    where
+        C: IntoIden,
+    {
+        Self::new(col, sea_query::Order::Asc)
+    }
+
+    /// Order by the given column, descending.
+    pub fn desc<C>(col: C) -> Self
+    where
+        C: IntoIden,
+    {
+        Self::new(col, sea_query::Order::Desc)
+    }
+}
+
 /// Indicates that this type can add filtering conditions to select statements.
+///
+/// This is implemented for `Box<dyn Filter>` and `&dyn Filter` to allow
+/// composing filters at runtime.
 pub trait Filter {
     fn filter_query(&self, _query: &mut SelectStatement) {
         // nothing by default
     }
+
+    /// Whether this filter adds no conditions, in which case
+    /// [`Filter::filter_query`] is never called and the filter is skipped
+    /// entirely.
+    ///
+    /// Only return `true` if [`Filter::filter_query`] would do nothing.
+    /// Returning `false` is always correct, so leave this as `false` for
+    /// filters that only sometimes add conditions.
+    fn is_noop(&self) -> bool {
+        false
+    }
+}
+
+/// A filter that adds no conditions.
+impl Filter for () {
+    fn is_noop(&self) -> bool {
+        true
+    }
+}
+
+impl<F> Filter for Box<F>
+where
+    F: Filter + ?Sized,
+{
+    fn filter_query(&self, query: &mut SelectStatement) {
+        (**self).filter_query(query)
+    }
+
+    fn is_noop(&self) -> bool {
+        (**self).is_noop()
+    }
+}
+
+impl<F> Filter for &F
+where
+    F: Filter + ?Sized,
+{
+    fn filter_query(&self, query: &mut SelectStatement) {
+        (**self).filter_query(query)
+    }
+
+    fn is_noop(&self) -> bool {
+        (**self).is_noop()
+    }
+}
+
+/// Wraps a [`Filter`] for use as a condition in arbitrary `sea_query`
+/// statements.
+///
+/// As filters operate on a select statement, the resulting condition is
+/// `id IN (SELECT id FROM table WHERE ...)`, with the filter applied to the
+/// subquery.
+#[derive(Debug, Clone)]
+pub struct FilterCondition<MC, F> {
+    filter: F,
+    _controller: PhantomData<fn() -> MC>,
+}
+
+impl<MC, F> FilterCondition<MC, F>
+where
+    MC: DbBmc,
+    F: Filter,
+{
+    pub fn new(filter: F) -> Self {
+        Self {
+            filter,
+            _controller: PhantomData,
+        }
+    }
+}
+
+impl<MC, F> IntoCondition for FilterCondition<MC, F>
+where
+    MC: DbBmc,
+    F: Filter,
+{
+    fn into_condition(self) -> Condition {
+        if self.filter.is_noop() {
+            return Condition::all();
+        }
+
+        Condition::all()
+            .add(Expr::col(MC::id_column()).in_subquery(filtered_ids::<MC, _>(&self.filter)))
+    }
 }
 
 /// Indicates that this type can be used as an ID for the purposes of model
@@ -64,7 +292,7 @@ where
 
 #[derive(Debug, Snafu)]
 pub enum DbBmcError {
-    #[snafu(display("Error performing {operation} for entity '{entity}': "))]
+    #[snafu(display("[ENTITY={entity}, OP={operation}]: "))]
     Operation {
         entity: &'static str,
         operation: DbBmcOp,
@@ -77,11 +305,23 @@ pub enum DbBmcError {
 pub enum DbBmcOp {
     Count,
     Create,
+    CreateMany,
     Delete,
+    DeleteAll,
+    DeleteWhere,
+    Exists,
+    Find,
+    First,
     Get,
+    GetMany,
+    Last,
     List,
     ListPaginated,
+    Restore,
+    SoftDelete,
     Update,
+    UpdateWhere,
+    Upsert,
 }
 
 impl Display for DbBmcOp {
@@ -89,11 +329,23 @@ impl Display for DbBmcOp {
         match self {
             DbBmcOp::Count => "COUNT",
             DbBmcOp::Create => "CREATE",
+            DbBmcOp::CreateMany => "CREATE MANY",
             DbBmcOp::Delete => "DELETE",
+            DbBmcOp::DeleteAll => "DELETE ALL",
+            DbBmcOp::DeleteWhere => "DELETE WHERE",
+            DbBmcOp::Exists => "EXISTS",
+            DbBmcOp::Find => "FIND",
+            DbBmcOp::First => "FIRST",
             DbBmcOp::Get => "GET",
+            DbBmcOp::GetMany => "GET MANY",
+            DbBmcOp::Last => "LAST",
             DbBmcOp::List => "LIST",
             DbBmcOp::ListPaginated => "LIST PAGINATED",
+            DbBmcOp::Restore => "RESTORE",
+            DbBmcOp::SoftDelete => "SOFT DELETE",
             DbBmcOp::Update => "UPDATE",
+            DbBmcOp::UpdateWhere => "UPDATE WHERE",
+            DbBmcOp::Upsert => "UPSERT",
         }
         .fmt(f)
     }
@@ -119,51 +371,260 @@ pub trait DbBmc {
     /// The name of the table in the database the entities reside in.
     const TABLE: &'static str;
 
+    /// The schema the table resides in, if not the default search path.
+    ///
+    /// When set, [`Self::get_table_ref`] qualifies the table with it, like
+    /// `"audit"."events"`.
+    const TABLE_SCHEMA: Option<&'static str> = None;
+
+    /// An alias for the table in queries, if any, like `b` for `books`.
+    ///
+    /// When set, [`Self::get_table_ref`] includes it, like `"books" AS "b"`,
+    /// so queries can refer to the table by the alias.
+    const TABLE_ALIAS: Option<&'static str> = None;
+
     type Error;
 
+    /// The default ordering of rows by their ID when listing.
+    ///
+    /// Paginated listing instead uses [`CursoredFilter::cursor_column_order`],
+    /// as the order determines which side of the cursor the page is on.
+    const DEFAULT_ORDER: sea_query::Order = sea_query::Order::Asc;
+
     /// The type of the ID column
     type IdType: IdType;
 
     fn id_column() -> DynIden;
 
+    /// The name of the id column, for use in error messages and the like.
+    ///
+    /// Implementors overriding [`Self::id_column`] should override this to
+    /// match.
+    fn id_column_name() -> &'static str {
+        "id"
+    }
+
     fn id_to_value(id: &Self::IdType) -> sea_query::SimpleExpr {
         id.id_value()
     }
 
+    /// The column marking rows as soft-deleted, if any, like `deleted_at`.
+    ///
+    /// When set, rows where this column is not `NULL` are excluded from
+    /// everything but [list_deleted], [restore], and the hard deletes, so
+    /// they're not fetched, counted, or updated. See [soft_delete].
+    const SOFT_DELETE_COLUMN: Option<&'static str> = None;
+
+    /// The column set to `NOW()` by [create], if any, like `created_at`.
+    ///
+    /// This is only set if the [`Insert`] does not already provide a value for
+    /// the column.
+    const CREATED_AT_COLUMN: Option<&'static str> = None;
+
+    /// The column set to `NOW()` by [create] and [update], if any, like
+    /// `updated_at`.
+    ///
+    /// As with [`DbBmc::CREATED_AT_COLUMN`], an explicit value from the
+    /// [`Insert`] or [`Update`] takes precedence.
+    const UPDATED_AT_COLUMN: Option<&'static str> = None;
+
     // provided methods
 
+    /// [`Self::ENTITY`] as a function, for use where a function is expected.
+    fn entity_name() -> &'static str {
+        Self::ENTITY
+    }
+
+    /// [`Self::TABLE`] as a function, for use where a function is expected.
+    fn table_name() -> &'static str {
+        Self::TABLE
+    }
+
+    /// A reference to [`Self::TABLE`] for use in queries, qualified with
+    /// [`Self::TABLE_SCHEMA`] and aliased as [`Self::TABLE_ALIAS`] if set.
+    ///
+    /// This can't be an associated const, as `TableRef` holds its `DynIden`
+    /// behind an `Arc`, and caching it in a static would need a lazy static
+    /// per implementor. Constructing it is cheap, however.
+    #[inline]
     fn get_table_ref() -> TableRef {
-        TableRef::Table(DynIden::new(Self::TABLE))
+        let table = DynIden::new(Self::TABLE);
+
+        match (Self::TABLE_SCHEMA, Self::TABLE_ALIAS) {
+            (Some(schema), Some(alias)) => {
+                TableRef::SchemaTableAlias(DynIden::new(schema), table, DynIden::new(alias))
+            }
+            (Some(schema), None) => TableRef::SchemaTable(DynIden::new(schema), table),
+            (None, Some(alias)) => TableRef::TableAlias(table, DynIden::new(alias)),
+            (None, None) => TableRef::Table(table),
+        }
+    }
+}
+
+// the alias of the id column when it's selected in addition to the model's
+// columns, which may not include it.
+const ID_ALIAS: &str = "bodega_id";
+
+// with the `tracing` feature, runs the statement in a `db.query` span for the
+// operation, logging the sql at `DEBUG`.
+#[cfg_attr(not(feature = "tracing"), allow(clippy::extra_unused_type_parameters))]
+async fn traced<MC, T>(operation: DbBmcOp, sql: &str, statement: impl Future<Output = T>) -> T
+where
+    MC: DbBmc,
+{
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::Instrument;
+
+        let span = tracing::info_span!(
+            "db.query",
+            entity = MC::ENTITY,
+            operation = %operation,
+            table = MC::TABLE,
+        );
+
+        async {
+            tracing::debug!(sql, "executing statement");
+            statement.await
+        }
+        .instrument(span)
+        .await
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (operation, sql);
+        statement.await
+    }
+}
+
+// exclude soft-deleted rows, if the model supports soft deletes.
+fn exclude_soft_deleted<MC>(query: &mut impl ConditionalStatement)
+where
+    MC: DbBmc,
+{
+    if let Some(col) = MC::SOFT_DELETE_COLUMN {
+        query.and_where(Expr::col(DynIden::new(col)).is_null());
+    }
+}
+
+// set any timestamp columns the insert doesn't already provide.
+fn insert_timestamps<MC>(cols: &mut Vec<DynIden>, vals: &mut Vec<SimpleExpr>)
+where
+    MC: DbBmc,
+{
+    for col in [MC::CREATED_AT_COLUMN, MC::UPDATED_AT_COLUMN]
+        .into_iter()
+        .flatten()
+    {
+        if !cols.iter().any(|c| c.to_string() == col) {
+            cols.push(DynIden::new(col));
+            vals.push(crate::expr::now());
+        }
+    }
+}
+
+// set the updated at column, if the update doesn't already provide it.
+fn update_timestamp<MC>(values: &mut Vec<(DynIden, SimpleExpr)>)
+where
+    MC: DbBmc,
+{
+    if let Some(col) = MC::UPDATED_AT_COLUMN {
+        if !values.iter().any(|(c, _)| c.to_string() == col) {
+            values.push((DynIden::new(col), crate::expr::now()));
+        }
     }
 }
 
+// Filters operate on select statements, so in order to use them with other
+// kinds of statements, we select the ids of the matching rows.
+fn filtered_ids<MC, F>(filter: &F) -> SelectStatement
+where
+    MC: DbBmc,
+    F: Filter,
+{
+    let mut query = Query::select();
+
+    query
+        .column(MC::id_column())
+        .from(MC::get_table_ref())
+        .apply_filter(filter);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    query
+}
+
+// the `COUNT` of the rows satisfying the filter.
+fn count_statement<MC, F>(filter: &F) -> SelectStatement
+where
+    MC: DbBmc,
+    F: Filter,
+{
+    let mut query = Query::select();
+
+    query
+        .expr(Expr::col(MC::id_column()).count())
+        .from(MC::get_table_ref())
+        .apply_filter(filter);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    query
+}
+
 /// Counts all of the rows in a model manager's table.
 pub async fn count<MC, X>(executor: &mut X) -> Result<usize>
 where
     MC: DbBmc,
     X: AsExecutor,
 {
-    let query = Query::select()
-        .expr(Expr::col(MC::id_column()).count())
-        .from(MC::get_table_ref())
-        .to_owned();
+    let query = count_statement::<MC, _>(&());
+
+    fetch_count::<MC, X>(executor, query, DbBmcOp::Count).await
+}
+
+/// Count the rows satisfying the given filter using the specified executor.
+pub async fn count_filtered<MC, X, F>(executor: &mut X, filter: &F) -> Result<usize>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter,
+{
+    let query = count_statement::<MC, _>(filter);
+
+    fetch_count::<MC, X>(executor, query, DbBmcOp::Count).await
+}
 
+/// Execute a `COUNT` query, converting the result to a `usize`.
+async fn fetch_count<MC, X>(
+    executor: &mut X,
+    query: SelectStatement,
+    operation: DbBmcOp,
+) -> Result<usize>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+{
     let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
-    let (num,) = sqlx::query_as_with::<_, (i64,), _>(&sql, values)
-        .fetch_one(executor.as_executor())
-        .await
-        .context(SqlxSnafu)
-        .context(OperationSnafu {
-            entity: MC::ENTITY,
-            operation: DbBmcOp::Count,
-        })?;
+    let (num,) = traced::<MC, _>(
+        operation,
+        &sql,
+        sqlx::query_as_with::<_, (i64,), _>(&sql, values).fetch_one(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation,
+    })?;
 
     // this should practically never fail, but fine.
     Ok(usize::try_from(num)
         .context(UsizeSnafu)
         .context(OperationSnafu {
             entity: MC::ENTITY,
-            operation: DbBmcOp::Count,
+            operation,
         })?)
 }
 
@@ -175,168 +636,1618 @@ where
     I: Insert,
     E: Select,
 {
+    let mut cols = data.insert_cols();
+    let mut vals = data.insert_vals();
+    insert_timestamps::<MC>(&mut cols, &mut vals);
+
     let mut query = Query::insert();
     query
         .into_table(MC::get_table_ref())
-        .columns(data.insert_cols())
-        .values_panic(data.insert_vals())
+        .columns(cols)
+        .values_panic(vals)
         .returning(Query::returning().columns(E::select_cols()));
 
     let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
 
-    let res = sqlx::query_as_with::<_, _, _>(&sql, values)
-        .fetch_one(executor.as_executor())
-        .await
-        .context(SqlxSnafu)
-        .context(OperationSnafu {
-            entity: MC::ENTITY,
-            operation: DbBmcOp::Create,
-        })?;
+    let res = traced::<MC, _>(
+        DbBmcOp::Create,
+        &sql,
+        sqlx::query_as_with::<_, _, _>(&sql, values).fetch_one(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::Create,
+    })?;
 
     Ok(res)
 }
 
-/// Get a row from the model manager's table using the specified id and executor.
-pub async fn get<MC, X, E>(executor: &mut X, id: &<MC as DbBmc>::IdType) -> Result<E>
+/// Insert a row, or update the existing row with the values from `update` if
+/// the insert conflicts on `conflict_cols`, returning the resulting row.
+///
+/// As the row would not be returned if nothing was updated, this returns
+/// [`Error::EmptyUpsert`] if `update` has no values, and
+/// [`Error::EmptyConflictColumns`] if `conflict_cols` is empty.
+pub async fn upsert<MC, X, I, U, E>(
+    executor: &mut X,
+    data: I,
+    conflict_cols: &[DynIden],
+    update: U,
+) -> Result<E>
 where
     MC: DbBmc,
     X: AsExecutor,
+    I: Insert,
+    U: Update,
     E: Select,
 {
-    let mut query = Query::select();
-
-    query
-        .from(MC::get_table_ref())
-        .columns(E::select_cols())
-        .and_where(Expr::col(MC::id_column()).eq(MC::id_to_value(id)));
+    let mut query = upsert_statement::<MC, _, _>(data, conflict_cols, update)?;
+    query.returning(Query::returning().columns(E::select_cols()));
 
     let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
 
-    let entity = sqlx::query_as_with(&sql, values)
-        .fetch_optional(executor.as_executor())
-        .await
-        .context(SqlxSnafu)
-        .context(OperationSnafu {
-            entity: MC::ENTITY,
-            operation: DbBmcOp::Get,
-        })?
-        .ok_or_else(|| Error::EntityNotFound {
-            entity: MC::ENTITY,
-            id: id.to_string(),
-        })?;
+    let res = traced::<MC, _>(
+        DbBmcOp::Upsert,
+        &sql,
+        sqlx::query_as_with::<_, _, _>(&sql, values).fetch_one(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::Upsert,
+    })?;
 
-    Ok(entity)
+    Ok(res)
 }
 
-/// List all rows from the model manager's table using the specified executor.
+// builds the insert statement for an upsert, rejecting updates that would
+// return no row and conflict targets postgres would reject.
+fn upsert_statement<MC, I, U>(
+    data: I,
+    conflict_cols: &[DynIden],
+    update: U,
+) -> Result<InsertStatement>
+where
+    MC: DbBmc,
+    I: Insert,
+    U: Update,
+{
+    if conflict_cols.is_empty() {
+        return Err(Error::EmptyConflictColumns { entity: MC::ENTITY });
+    }
+
+    let mut update_values = update.update_values();
+    if update_values.is_empty() {
+        return Err(Error::EmptyUpsert { entity: MC::ENTITY });
+    }
+
+    update_timestamp::<MC>(&mut update_values);
+
+    let mut cols = data.insert_cols();
+    let mut vals = data.insert_vals();
+    insert_timestamps::<MC>(&mut cols, &mut vals);
+
+    let mut query = Query::insert();
+    query
+        .into_table(MC::get_table_ref())
+        .columns(cols)
+        .values_panic(vals)
+        .on_conflict(
+            OnConflict::columns(conflict_cols.iter().cloned())
+                .values(update_values)
+                .to_owned(),
+        );
+
+    Ok(query)
+}
+
+/// Insert many rows into the model manager's table in a single statement,
+/// returning the created rows.
 ///
-/// If you need pagination/filtering, use [list_paginated].
-pub async fn list<MC, X, E>(executor: &mut X) -> Result<Vec<E>>
+/// All of the rows must insert the same columns.
+pub async fn create_many<MC, X, I, E>(executor: &mut X, data: Vec<I>) -> Result<Vec<E>>
 where
     MC: DbBmc,
     X: AsExecutor,
+    I: Insert,
     E: Select,
 {
-    let mut query = Query::select();
-
-    query.from(MC::get_table_ref()).columns(E::select_cols());
+    let mut query = insert_many_statement::<MC, _>(data)?;
+    query.returning(Query::returning().columns(E::select_cols()));
 
     let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
 
-    let entities: Vec<E> = sqlx::query_as_with(&sql, values)
-        .fetch_all(executor.as_executor())
-        .await
-        .context(SqlxSnafu)
-        .context(OperationSnafu {
-            entity: MC::ENTITY,
-            operation: DbBmcOp::List,
-        })?;
+    let entities: Vec<E> = traced::<MC, _>(
+        DbBmcOp::CreateMany,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::CreateMany,
+    })?;
 
     Ok(entities)
 }
 
-/// Get a page of rows from the model manager's table using the specified executor and filters.
+/// Like [create_many], but inserting at most `chunk_size` rows per statement.
 ///
-/// If you want to just list all rows, use [list]
-pub async fn list_paginated<MC, X, F, E>(executor: &mut X, filter: &F) -> Result<Paginated<E>>
+/// Postgres limits a statement to 65535 bind parameters, and each row binds
+/// one parameter per column, so `chunk_size * columns` must stay below that.
+/// Something like 1000 is a reasonably safe default for most tables.
+///
+/// Each chunk is a separate statement, so use a transaction if all of the rows
+/// should be inserted or none of them. Returns [`Error::InvalidChunkSize`] if
+/// `chunk_size` is 0.
+pub async fn create_many_chunked<MC, X, I, E>(
+    executor: &mut X,
+    data: Vec<I>,
+    chunk_size: usize,
+) -> Result<Vec<E>>
 where
     MC: DbBmc,
     X: AsExecutor,
-    F: Filter + CursoredFilter,
-    E: Select + Cursored,
+    I: Insert,
+    E: Select,
 {
-    let mut query = Query::select();
-
-    query
-        .from(MC::get_table_ref())
-        .columns(E::select_cols())
-        .order_by(E::cursor_column(), F::cursor_column_order())
-        .limit(filter.page_limit() as u64);
+    if chunk_size == 0 {
+        return Err(Error::InvalidChunkSize { entity: MC::ENTITY });
+    }
 
-    filter.filter_query(&mut query);
+    let mut out = Vec::with_capacity(data.len());
+    let mut data = data.into_iter().peekable();
 
-    if let Some(cursor) = filter.cursor() {
-        if F::cursor_column_order() == sea_query::Order::Asc {
-            query.and_where(Expr::col(E::cursor_column()).gt(cursor));
-        } else {
-            query.and_where(Expr::col(E::cursor_column()).lt(cursor));
-        }
+    while data.peek().is_some() {
+        let chunk: Vec<I> = data.by_ref().take(chunk_size).collect();
+        out.extend(create_many::<MC, X, I, E>(executor, chunk).await?);
     }
 
+    Ok(out)
+}
+
+/// Insert new rows into the model manager's table using the specified
+/// executor, returning only the ids of the created rows.
+///
+/// All of the rows are inserted with a single statement, so every entry in
+/// `data` must insert the same columns.
+pub async fn insert_many_ids<MC, X, I>(
+    executor: &mut X,
+    data: Vec<I>,
+) -> Result<Vec<<MC as DbBmc>::IdType>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    I: Insert,
+    <MC as DbBmc>::IdType: for<'r> sqlx::Decode<'r, Postgres>,
+{
+    let mut query = insert_many_statement::<MC, _>(data)?;
+    query.returning_col(MC::id_column());
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let ids: Vec<(<MC as DbBmc>::IdType,)> = traced::<MC, _>(
+        DbBmcOp::CreateMany,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::CreateMany,
+    })?;
+
+    Ok(ids.into_iter().map(|(id,)| id).collect())
+}
+
+// builds a single insert statement for all of the given data, ensuring that
+// every entry is inserting the same columns.
+pub(crate) fn insert_many_statement<MC, I>(data: Vec<I>) -> Result<InsertStatement>
+where
+    MC: DbBmc,
+    I: Insert,
+{
+    let Some(first) = data.first() else {
+        return Err(Error::EmptyInsert { entity: MC::ENTITY });
+    };
+
+    let mut cols = first.insert_cols();
+    insert_timestamps::<MC>(&mut cols, &mut Vec::new());
+    let col_names: Vec<String> = cols.iter().map(|c| c.to_string()).collect();
+
+    let mut query = Query::insert();
+    query.into_table(MC::get_table_ref()).columns(cols);
+
+    for entry in data {
+        let mut entry_cols = entry.insert_cols();
+        let mut vals = entry.insert_vals();
+        insert_timestamps::<MC>(&mut entry_cols, &mut vals);
+
+        let matches = entry_cols
+            .iter()
+            .map(|c| c.to_string())
+            .eq(col_names.iter().cloned());
+
+        if !matches {
+            return Err(Error::InconsistentInsert { entity: MC::ENTITY });
+        }
+
+        query.values_panic(vals);
+    }
+
+    Ok(query)
+}
+
+/// Get a row from the model manager's table using the specified id and executor.
+pub async fn get<MC, X, E>(executor: &mut X, id: &<MC as DbBmc>::IdType) -> Result<E>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    get_optional::<MC, X, E>(executor, id)
+        .await?
+        .ok_or_else(|| Error::EntityNotFound {
+            entity: MC::ENTITY,
+            id: id.to_string(),
+        })
+}
+
+/// Like [get], but returning `None` instead of [`Error::EntityNotFound`] if
+/// there is no row with the given id.
+pub async fn get_optional<MC, X, E>(
+    executor: &mut X,
+    id: &<MC as DbBmc>::IdType,
+) -> Result<Option<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .columns(E::select_cols())
+        .and_where(Expr::col(MC::id_column()).eq(MC::id_to_value(id)));
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entity = traced::<MC, _>(
+        DbBmcOp::Get,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_optional(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::Get,
+    })?;
+
+    Ok(entity)
+}
+
+/// Fetch a single row satisfying the given filter, returning
+/// [`Error::EntityNotFoundWhere`] if there are none.
+///
+/// This is intended for lookups by unique columns other than the id. If more
+/// than one row satisfies the filter, the first according to
+/// [`DbBmc::DEFAULT_ORDER`] is returned.
+pub async fn find<MC, X, F, E>(executor: &mut X, filter: &F) -> Result<E>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter,
+    E: Select,
+{
+    find_optional::<MC, X, F, E>(executor, filter)
+        .await?
+        .ok_or(Error::EntityNotFoundWhere { entity: MC::ENTITY })
+}
+
+/// Like [find], but returning `None` if no rows satisfy the filter.
+pub async fn find_optional<MC, X, F, E>(executor: &mut X, filter: &F) -> Result<Option<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter,
+    E: Select,
+{
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .columns(E::select_cols())
+        .apply_filter(filter)
+        .order_by(MC::id_column(), MC::DEFAULT_ORDER)
+        .limit(1);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entity = traced::<MC, _>(
+        DbBmcOp::Find,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_optional(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::Find,
+    })?;
+
+    Ok(entity)
+}
+
+/// Fetch all rows with any of the given ids.
+///
+/// Ids without a corresponding row are ignored, so the result may contain
+/// fewer rows than ids. The rows are not guaranteed to be in the order of
+/// `ids`.
+pub async fn get_many_by_ids<MC, X, E>(
+    executor: &mut X,
+    ids: &[<MC as DbBmc>::IdType],
+) -> Result<Vec<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .columns(E::select_cols())
+        .and_where(eq_any(
+            MC::id_column(),
+            ids.iter().map(MC::id_to_value).collect(),
+        ));
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entities: Vec<E> = traced::<MC, _>(
+        DbBmcOp::GetMany,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::GetMany,
+    })?;
+
+    Ok(entities)
+}
+
+/// Fetch the rows with the given ids in a single query, in the same order as
+/// the ids, returning [`Error::EntityNotFound`] for the first id with no row.
+///
+/// Returns [`Error::DuplicateId`] if an id appears more than once. See
+/// [get_many_optional] to not fail on missing rows, and [get_many_by_ids] to
+/// not care about the order.
+pub async fn get_many<MC, X, E>(executor: &mut X, ids: &[<MC as DbBmc>::IdType]) -> Result<Vec<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+    <MC as DbBmc>::IdType: for<'r> sqlx::Decode<'r, Postgres>,
+{
+    let mut by_id = fetch_by_ids::<MC, X, E>(executor, ids).await?;
+
+    ids.iter()
+        .map(|id| {
+            let key = id.to_string();
+            by_id.remove(&key).ok_or(Error::EntityNotFound {
+                entity: MC::ENTITY,
+                id: key,
+            })
+        })
+        .collect()
+}
+
+/// Like [get_many], but returning an entry for every id, which is `None` if
+/// there is no row with that id.
+///
+/// Returns [`Error::DuplicateId`] if an id appears more than once.
+pub async fn get_many_optional<MC, X, E>(
+    executor: &mut X,
+    ids: &[<MC as DbBmc>::IdType],
+) -> Result<Vec<Option<E>>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+    <MC as DbBmc>::IdType: for<'r> sqlx::Decode<'r, Postgres>,
+{
+    let mut by_id = fetch_by_ids::<MC, X, E>(executor, ids).await?;
+
+    Ok(ids.iter().map(|id| by_id.remove(&id.to_string())).collect())
+}
+
+// every row is moved out of the fetched map into the position of its id, so
+// repeated ids are rejected rather than silently missing.
+fn ensure_unique_ids<MC: DbBmc>(ids: &[<MC as DbBmc>::IdType]) -> Result<()> {
+    let mut seen = HashSet::with_capacity(ids.len());
+
+    match ids
+        .iter()
+        .map(|id| id.to_string())
+        .find(|id| !seen.insert(id.clone()))
+    {
+        Some(id) => Err(Error::DuplicateId {
+            entity: MC::ENTITY,
+            id,
+        }),
+        None => Ok(()),
+    }
+}
+
+// fetch the rows with the given ids, keyed by the string form of their id, as
+// ids are not required to be `Hash`.
+async fn fetch_by_ids<MC, X, E>(
+    executor: &mut X,
+    ids: &[<MC as DbBmc>::IdType],
+) -> Result<HashMap<String, E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+    <MC as DbBmc>::IdType: for<'r> sqlx::Decode<'r, Postgres>,
+{
+    ensure_unique_ids::<MC>(ids)?;
+
+    if ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .columns(E::select_cols())
+        .expr_as(Expr::col(MC::id_column()), DynIden::new(ID_ALIAS))
+        .and_where(eq_any(
+            MC::id_column(),
+            ids.iter().map(MC::id_to_value).collect(),
+        ));
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let rows: Vec<PgRow> = traced::<MC, _>(
+        DbBmcOp::GetMany,
+        &sql,
+        sqlx::query_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::GetMany,
+    })?;
+
+    rows.iter()
+        .map(|row| {
+            let id: <MC as DbBmc>::IdType = row.try_get(ID_ALIAS)?;
+            Ok((id.to_string(), E::from_row(row)?))
+        })
+        .collect::<std::result::Result<_, sqlx::Error>>()
+        .context(SqlxSnafu)
+        .context(OperationSnafu {
+            entity: MC::ENTITY,
+            operation: DbBmcOp::GetMany,
+        })
+        .map_err(Into::into)
+}
+
+/// Check if a row with the given id exists, using `SELECT EXISTS(...)`.
+pub async fn exists<MC, X>(executor: &mut X, id: &<MC as DbBmc>::IdType) -> Result<bool>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+{
+    let subquery = Query::select()
+        .expr(Expr::val(1))
+        .from(MC::get_table_ref())
+        .and_where(Expr::col(MC::id_column()).eq(MC::id_to_value(id)))
+        .to_owned();
+
+    select_exists::<MC, X>(executor, subquery).await
+}
+
+/// Check if any rows match the given filter, using `SELECT EXISTS(...)`.
+pub async fn exists_where<MC, X, F>(executor: &mut X, filter: &F) -> Result<bool>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter,
+{
+    let subquery = Query::select()
+        .expr(Expr::val(1))
+        .from(MC::get_table_ref())
+        .apply_filter(filter)
+        .to_owned();
+
+    select_exists::<MC, X>(executor, subquery).await
+}
+
+async fn select_exists<MC, X>(executor: &mut X, mut subquery: SelectStatement) -> Result<bool>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+{
+    exclude_soft_deleted::<MC>(&mut subquery);
+
+    let query = Query::select().expr(Expr::exists(subquery)).to_owned();
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let (exists,) = traced::<MC, _>(
+        DbBmcOp::Exists,
+        &sql,
+        sqlx::query_as_with::<_, (bool,), _>(&sql, values).fetch_one(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::Exists,
+    })?;
+
+    Ok(exists)
+}
+
+/// Check if a row with the given id exists, without fetching any columns.
+///
+/// This is equivalent to [exists].
+pub async fn exists_by_id<MC, X>(executor: &mut X, id: &<MC as DbBmc>::IdType) -> Result<bool>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+{
+    exists::<MC, X>(executor, id).await
+}
+
+/// Fetch the row with the lowest id, if any.
+pub async fn first<MC, X, E>(executor: &mut X) -> Result<Option<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    get_by_order::<MC, X, E>(executor, sea_query::Order::Asc, DbBmcOp::First).await
+}
+
+/// Fetch the row with the highest id, if any.
+pub async fn last<MC, X, E>(executor: &mut X) -> Result<Option<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    get_by_order::<MC, X, E>(executor, sea_query::Order::Desc, DbBmcOp::Last).await
+}
+
+async fn get_by_order<MC, X, E>(
+    executor: &mut X,
+    order: sea_query::Order,
+    operation: DbBmcOp,
+) -> Result<Option<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .columns(E::select_cols())
+        .order_by(MC::id_column(), order)
+        .limit(1);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entity = traced::<MC, _>(
+        operation,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_optional(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation,
+    })?;
+
+    Ok(entity)
+}
+
+/// List all rows from the model manager's table using the specified executor.
+///
+/// Rows are ordered by id, according to [`DbBmc::DEFAULT_ORDER`].
+///
+/// If you need pagination/filtering, use [list_paginated].
+pub async fn list<MC, X, E>(executor: &mut X) -> Result<Vec<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .columns(E::select_cols())
+        .order_by(MC::id_column(), MC::DEFAULT_ORDER);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entities: Vec<E> = traced::<MC, _>(
+        DbBmcOp::List,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::List,
+    })?;
+
+    Ok(entities)
+}
+
+/// List rows from the model manager's table according to the given options.
+///
+/// See [`ListOptions`].
+pub async fn list_with_options<MC, X, E>(executor: &mut X, options: &ListOptions) -> Result<Vec<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    let mut query = Query::select();
+
+    query.from(MC::get_table_ref()).columns(E::select_cols());
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    match options.order_by {
+        Some((ref col, ref order)) => query.order_by(col.clone(), order.clone()),
+        None => query.order_by(MC::id_column(), MC::DEFAULT_ORDER),
+    };
+
+    if let Some(limit) = options.limit {
+        query.limit(limit);
+    }
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entities: Vec<E> = traced::<MC, _>(
+        DbBmcOp::List,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::List,
+    })?;
+
+    Ok(entities)
+}
+
+/// List all rows from the model manager's table, ordered by each of the given
+/// orderings in turn.
+///
+/// With no orderings, rows are ordered by id, like [list]. Note that rows that
+/// compare equal on every ordering are returned in an unspecified order, so
+/// ending with a unique column (e.g. `Ordering::asc(id)`) makes the order
+/// deterministic.
+pub async fn list_ordered<MC, X, E>(executor: &mut X, orderings: &[Ordering]) -> Result<Vec<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    let mut query = Query::select();
+
+    query.from(MC::get_table_ref()).columns(E::select_cols());
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    if orderings.is_empty() {
+        query.order_by(MC::id_column(), MC::DEFAULT_ORDER);
+    }
+
+    for ordering in orderings {
+        query.order_by(ordering.col.clone(), ordering.order.clone());
+    }
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entities: Vec<E> = traced::<MC, _>(
+        DbBmcOp::List,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::List,
+    })?;
+
+    Ok(entities)
+}
+
+/// List all rows satisfying the given filter using the specified executor.
+///
+/// Unlike [list_paginated], no cursor conditions or `LIMIT` are applied, so
+/// every matching row is returned, ordered according to
+/// [`DbBmc::DEFAULT_ORDER`].
+pub async fn list_filtered<MC, X, F, E>(executor: &mut X, filter: &F) -> Result<Vec<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter,
+    E: Select,
+{
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .columns(E::select_cols())
+        .apply_filter(filter)
+        .order_by(MC::id_column(), MC::DEFAULT_ORDER);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entities: Vec<E> = traced::<MC, _>(
+        DbBmcOp::List,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::List,
+    })?;
+
+    Ok(entities)
+}
+
+/// Get a page of rows from the model manager's table using the specified executor and filters.
+///
+/// The direction of the page relative to the cursor is determined by
+/// [`CursoredFilter::cursor_column_order`], so with the default ascending
+/// order, this is equivalent to [list_paginated_after].
+///
+/// If the filter has a [`CursoredFilter::prev_cursor`], the page of rows
+/// _preceding_ that cursor is returned instead, still in the natural order of
+/// the filter. If either cursor was supplied, the `prev_cursor` of the
+/// returned page is the cursor of its first entry, if any, so it can be used
+/// to walk back a page. The first page has no `prev_cursor`.
+///
+/// If you want to just list all rows, use [list]
+pub async fn list_paginated<MC, X, F, E>(executor: &mut X, filter: &F) -> Result<Paginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter + CursoredFilter,
+    E: Select + Cursored,
+{
+    let order = F::cursor_column_order();
+    let resumed = filter.cursor().is_some() || filter.prev_cursor().is_some();
+
+    let mut page = match filter.prev_cursor() {
+        Some(prev) => {
+            let natural_nulls_last = nulls_last::<F>(&order);
+            let reversed = match order {
+                sea_query::Order::Asc => sea_query::Order::Desc,
+                sea_query::Order::Desc => sea_query::Order::Asc,
+                other => other,
+            };
+
+            // the nulls flip along with the order, so that reversing the
+            // entries results in the natural order.
+            let mut page = list_paginated_page::<MC, X, F, E, _>(
+                executor,
+                filter,
+                Some(prev),
+                filter.page_limit(),
+                None,
+                reversed,
+                !natural_nulls_last,
+            )
+            .await?;
+
+            // we walked backwards, so there's always a page after this one.
+            page.entries.reverse();
+            page.next_cursor = page.entries.last().map(|e| e.cursor_value());
+
+            page
+        }
+        None => {
+            list_paginated_by::<MC, X, F, E>(executor, filter, filter.page_limit(), order).await?
+        }
+    };
+
+    if resumed {
+        page.prev_cursor = page.entries.first().map(|e| e.cursor_value());
+    }
+
+    Ok(page)
+}
+
+/// Resume listing from a known cursor, using the default filter `F`.
+///
+/// As there's no way to set the limit on a [`CursoredFilter`], `limit` is
+/// used in place of the page limit of the filter.
+pub async fn list_paginated_from_cursor<MC, X, F, E>(
+    executor: &mut X,
+    cursor: <E as Cursored>::CursorType,
+    limit: usize,
+) -> Result<Paginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Default + Filter + CursoredFilter<Entity = E>,
+    E: Select + Cursored,
+{
+    let mut filter = F::default();
+    filter.set_cursor(cursor);
+
+    list_paginated_by::<MC, X, F, E>(executor, &filter, limit, F::cursor_column_order()).await
+}
+
+/// Get a page of up to `limit` rows after `cursor`, or from the beginning if
+/// `cursor` is `None`, without any other filtering.
+///
+/// This avoids needing to define a [`CursoredFilter`] when the cursor is the
+/// only filter. Rows are in ascending order of the cursor column.
+pub async fn list_by_cursor<MC, X, E>(
+    executor: &mut X,
+    cursor: Option<<E as Cursored>::CursorType>,
+    limit: usize,
+) -> Result<Paginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select + Cursored,
+{
+    list_paginated_page::<MC, X, (), E, _>(
+        executor,
+        &(),
+        cursor,
+        limit,
+        None,
+        sea_query::Order::Asc,
+        true,
+    )
+    .await
+}
+
+/// Like [list_paginated], but with an explicit order in place of
+/// [`CursoredFilter::cursor_column_order`], optionally ordering by `order_by`
+/// before the cursor column.
+///
+/// Pages are still determined by the cursor, so `order_by` should be a column
+/// whose values increase along with the cursor (e.g. `created_at` with a
+/// time-ordered id), or pages may skip or repeat rows.
+pub async fn list_paginated_ordered<MC, X, F, E>(
+    executor: &mut X,
+    filter: &F,
+    order_by: Option<DynIden>,
+    order: sea_query::Order,
+) -> Result<Paginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter + CursoredFilter,
+    E: Select + Cursored,
+{
+    list_paginated_by_column::<MC, X, F, E>(executor, filter, filter.page_limit(), order_by, order)
+        .await
+}
+
+/// Get the page of rows ordered _after_ the cursor of the filter, in
+/// ascending order, regardless of [`CursoredFilter::cursor_column_order`].
+pub async fn list_paginated_after<MC, X, F, E>(executor: &mut X, filter: &F) -> Result<Paginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter + CursoredFilter,
+    E: Select + Cursored,
+{
+    list_paginated_by::<MC, X, F, E>(executor, filter, filter.page_limit(), sea_query::Order::Asc)
+        .await
+}
+
+/// Get the page of rows ordered _before_ the cursor of the filter, regardless
+/// of [`CursoredFilter::cursor_column_order`].
+///
+/// The entries of the page are in ascending order, like
+/// [list_paginated_after], but the next cursor points to the first entry, so
+/// that following it continues walking backwards. The same cursor is also
+/// available as the page's `prev_cursor`, so [`Paginated::has_previous`]
+/// indicates whether there may be more rows before this page.
+pub async fn list_paginated_before<MC, X, F, E>(
+    executor: &mut X,
+    filter: &F,
+) -> Result<Paginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter + CursoredFilter,
+    E: Select + Cursored,
+{
+    let mut page = list_paginated_by::<MC, X, F, E>(
+        executor,
+        filter,
+        filter.page_limit(),
+        sea_query::Order::Desc,
+    )
+    .await?;
+
+    page.entries.reverse();
+    page.prev_cursor = page.next_cursor.clone();
+
+    Ok(page)
+}
+
+/// [list_paginated], but with an explicit limit and order instead of those of
+/// the filter.
+async fn list_paginated_by<MC, X, F, E>(
+    executor: &mut X,
+    filter: &F,
+    limit: usize,
+    order: sea_query::Order,
+) -> Result<Paginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter + CursoredFilter,
+    E: Select + Cursored,
+{
+    list_paginated_by_column::<MC, X, F, E>(executor, filter, limit, None, order).await
+}
+
+/// [list_paginated_by], but first ordering by `order_by`, if present.
+async fn list_paginated_by_column<MC, X, F, E>(
+    executor: &mut X,
+    filter: &F,
+    limit: usize,
+    order_by: Option<DynIden>,
+    order: sea_query::Order,
+) -> Result<Paginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter + CursoredFilter,
+    E: Select + Cursored,
+{
+    list_paginated_page::<MC, X, F, E, _>(
+        executor,
+        filter,
+        filter.cursor(),
+        limit,
+        order_by,
+        order.clone(),
+        nulls_last::<F>(&order),
+    )
+    .await
+}
+
+// whether NULLs are ordered last for the given order, as configured by the
+// filter or by default.
+fn nulls_last<F: CursoredFilter>(order: &sea_query::Order) -> bool {
+    F::cursor_nulls_last().unwrap_or(*order == sea_query::Order::Asc)
+}
+
+/// [list_paginated_by_column], but starting from `cursor` instead of the
+/// cursor of the filter, with `NULL`s ordered last if `nulls_last`.
+async fn list_paginated_page<MC, X, F, E, C>(
+    executor: &mut X,
+    filter: &F,
+    cursor: Option<C>,
+    limit: usize,
+    order_by: Option<DynIden>,
+    order: sea_query::Order,
+    nulls_last: bool,
+) -> Result<Paginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter,
+    E: Select + Cursored,
+    C: Into<SimpleExpr>,
+{
+    let query =
+        list_paginated_statement::<MC, F, E, C>(filter, cursor, limit, order_by, order, nulls_last);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entities: Vec<E> = traced::<MC, _>(
+        DbBmcOp::ListPaginated,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::ListPaginated,
+    })?;
+
+    Ok(Paginated::new(entities, limit))
+}
+
+// the select statement for a page of rows after the cursor.
+fn list_paginated_statement<MC, F, E, C>(
+    filter: &F,
+    cursor: Option<C>,
+    limit: usize,
+    order_by: Option<DynIden>,
+    order: sea_query::Order,
+    nulls_last: bool,
+) -> SelectStatement
+where
+    MC: DbBmc,
+    F: Filter,
+    E: Select + Cursored,
+    C: Into<SimpleExpr>,
+{
+    let mut query = Query::select();
+
+    query.from(MC::get_table_ref()).select_model::<E>();
+
+    let nulls = if nulls_last {
+        NullOrdering::Last
+    } else {
+        NullOrdering::First
+    };
+
+    if let Some(col) = order_by {
+        query.order_by_with_nulls(col, order.clone(), nulls);
+    }
+
+    query
+        .paginate_cursor_columns_with_nulls(E::cursor_columns(), cursor, order, nulls)
+        .limit(limit as u64)
+        .apply_filter(filter);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    query
+}
+
+/// Get a page of rows satisfying the filter by offset, rather than by cursor.
+///
+/// `page` is zero-based, so the rows skipped are `page * page_size`. This
+/// issues two queries, one for the page of rows and one for the total count
+/// of rows satisfying the filter, so, unless run in a transaction, the count
+/// may not be consistent with the entries.
+///
+/// Prefer [list_paginated] where possible, as large offsets require the
+/// database to scan all of the skipped rows.
+pub async fn list_offset_paginated<MC, X, F, E>(
+    executor: &mut X,
+    filter: &F,
+    page: usize,
+    page_size: usize,
+) -> Result<OffsetPaginated<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter,
+    E: Select,
+{
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .select_model::<E>()
+        .apply_filter(filter)
+        .order_by(MC::id_column(), MC::DEFAULT_ORDER)
+        .limit(page_size as u64)
+        .offset(page.saturating_mul(page_size) as u64);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entries: Vec<E> = traced::<MC, _>(
+        DbBmcOp::ListPaginated,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::ListPaginated,
+    })?;
+
+    let total_count = count_filtered::<MC, X, F>(executor, filter).await?;
+
+    Ok(OffsetPaginated {
+        entries,
+        total_count,
+        page,
+        page_size,
+    })
+}
+
+/// Walk all rows matching `filter`, `chunk_size` rows at a time, calling `f`
+/// with each chunk.
+///
+/// Pages are fetched by cursor starting from the cursor of `filter` (if any),
+/// so only a single chunk is held in memory at a time. The page limit of
+/// `filter` is ignored in favor of `chunk_size`. Returning an error from `f`
+/// will stop processing and return that error. Returns
+/// [`Error::InvalidChunkSize`] if `chunk_size` is 0.
+pub async fn list_in_chunks<MC, X, F, E, C>(
+    executor: &mut X,
+    mut filter: F,
+    chunk_size: usize,
+    mut f: C,
+) -> Result<()>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter + CursoredFilter<Entity = E>,
+    E: Select + Cursored,
+    C: AsyncFnMut(Vec<E>) -> Result<()>,
+{
+    if chunk_size == 0 {
+        return Err(Error::InvalidChunkSize { entity: MC::ENTITY });
+    }
+
+    loop {
+        let page = list_paginated_by::<MC, X, F, E>(
+            executor,
+            &filter,
+            chunk_size,
+            F::cursor_column_order(),
+        )
+        .await?;
+        let next_cursor = page.next_cursor;
+
+        if !page.entries.is_empty() {
+            f(page.entries).await?;
+        }
+
+        match next_cursor {
+            Some(cursor) => filter.set_cursor(cursor),
+            None => return Ok(()),
+        }
+    }
+}
+
+// the sides of the comparison against the cursor, with compound cursors spread
+// over their columns, and the id column and value appended if given, like
+// `("created_at", "id") > ('...', 5)`.
+fn stable_cursor_bound(
+    cursor_cols: Vec<DynIden>,
+    cursor: SimpleExpr,
+    id: Option<(DynIden, SimpleExpr)>,
+) -> (Expr, SimpleExpr) {
+    let mut vals = match cursor {
+        SimpleExpr::Tuple(vals) if cursor_cols.len() > 1 => vals,
+        cursor => vec![cursor],
+    };
+    let mut cols: Vec<SimpleExpr> = cursor_cols
+        .into_iter()
+        .map(|col| Expr::col(col).into())
+        .collect();
+
+    if let Some((id_col, id_val)) = id {
+        cols.push(Expr::col(id_col).into());
+        vals.push(id_val);
+    }
+
+    if cols.len() == 1 && vals.len() == 1 {
+        (Expr::expr(cols.remove(0)), vals.remove(0))
+    } else {
+        (Expr::tuple(cols), Expr::tuple(vals).into())
+    }
+}
+
+/// Like [list_paginated], but always sorts by the id as a tiebreaker.
+///
+/// Paginating by a non-unique cursor column (e.g. `created_at`) can skip or
+/// repeat rows that share a cursor value. To avoid this, the `next_cursor` of
+/// the returned page is the cursor and id of its last entry. Set the cursor of
+/// the filter to the former and pass the latter as `after_id` to get the next
+/// page, which will compare them as `(cursor_col, id) > (cursor, after_id)`.
+/// Compound cursors compare each of their columns, like
+/// `(created_at, name, id) > (a, b, after_id)`.
+pub async fn list_paginated_stable<MC, X, F, E>(
+    executor: &mut X,
+    filter: &F,
+    after_id: Option<&<MC as DbBmc>::IdType>,
+) -> Result<StablePaginated<E, <MC as DbBmc>::IdType>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter + CursoredFilter,
+    E: Select + Cursored,
+    <MC as DbBmc>::IdType: for<'r> sqlx::Decode<'r, Postgres>,
+{
+    let order = F::cursor_column_order();
+    let limit = filter.page_limit();
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .select_model::<E>()
+        .expr_as(Expr::col(MC::id_column()), DynIden::new(ID_ALIAS));
+
+    for col in E::cursor_columns() {
+        query.order_by(col, order.clone());
+    }
+
+    query
+        .order_by(MC::id_column(), order.clone())
+        .limit(limit as u64)
+        .apply_filter(filter);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    if let Some(cursor) = filter.cursor() {
+        let (lhs, rhs) = stable_cursor_bound(
+            E::cursor_columns(),
+            cursor.into(),
+            after_id.map(|id| (MC::id_column(), MC::id_to_value(id))),
+        );
+
+        if order == sea_query::Order::Asc {
+            query.and_where(lhs.gt(rhs));
+        } else {
+            query.and_where(lhs.lt(rhs));
+        }
+    }
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let rows: Vec<PgRow> = traced::<MC, _>(
+        DbBmcOp::ListPaginated,
+        &sql,
+        sqlx::query_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::ListPaginated,
+    })?;
+
+    let decoded = || -> std::result::Result<_, sqlx::Error> {
+        let entries = rows
+            .iter()
+            .map(E::from_row)
+            .collect::<std::result::Result<Vec<E>, _>>()?;
+
+        // as with `Paginated::new`, a full page may have more entries after it
+        let next_cursor = match (entries.last(), rows.last()) {
+            (Some(entry), Some(row)) if entries.len() >= limit => {
+                let id: <MC as DbBmc>::IdType = row.try_get(ID_ALIAS)?;
+                Some((entry.cursor_value(), id))
+            }
+            _ => None,
+        };
+
+        Ok(StablePaginated {
+            entries,
+            next_cursor,
+            limit,
+        })
+    };
+
+    decoded()
+        .context(SqlxSnafu)
+        .context(OperationSnafu {
+            entity: MC::ENTITY,
+            operation: DbBmcOp::ListPaginated,
+        })
+        .map_err(Into::into)
+}
+
+/// Update a row in the model manager's table using the specified executor, id, and data.
+pub async fn update<MC, X, U, E>(executor: &mut X, id: &<MC as DbBmc>::IdType, data: U) -> Result<E>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    U: Update,
+    E: Select,
+{
+    let mut values = data.update_values();
+    if values.is_empty() {
+        return Err(Error::EmptyUpdate {
+            entity: MC::ENTITY,
+            id: id.to_string(),
+        });
+    }
+
+    update_timestamp::<MC>(&mut values);
+
+    let mut query = Query::update();
+
+    query
+        .table(MC::get_table_ref())
+        .values(values)
+        .and_where(Expr::col(MC::id_column()).eq(MC::id_to_value(id)))
+        .returning(Query::returning().columns(E::select_cols()));
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entity = traced::<MC, _>(
+        DbBmcOp::Update,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_optional(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::Update,
+    })?
+    .ok_or_else(|| Error::EntityNotFound {
+        entity: MC::ENTITY,
+        id: id.to_string(),
+    })?;
+
+    Ok(entity)
+}
+
+/// Update the row with the given id without changing any of its values,
+/// returning the row.
+///
+/// This sets [`DbBmc::UPDATED_AT_COLUMN`] to `NOW()` if configured, and
+/// otherwise sets the id column to itself, so any `UPDATE` triggers (e.g.
+/// ones maintaining an `updated_at` column) still fire.
+pub async fn touch<MC, X, E>(executor: &mut X, id: &<MC as DbBmc>::IdType) -> Result<E>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    let mut values = Vec::new();
+    update_timestamp::<MC>(&mut values);
+
+    if values.is_empty() {
+        values.push((MC::id_column(), Expr::col(MC::id_column()).into()));
+    }
+
+    let mut query = Query::update();
+
+    query
+        .table(MC::get_table_ref())
+        .values(values)
+        .and_where(Expr::col(MC::id_column()).eq(MC::id_to_value(id)))
+        .returning(Query::returning().columns(E::select_cols()));
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entity = traced::<MC, _>(
+        DbBmcOp::Update,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_optional(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::Update,
+    })?
+    .ok_or_else(|| Error::EntityNotFound {
+        entity: MC::ENTITY,
+        id: id.to_string(),
+    })?;
+
+    Ok(entity)
+}
+
+/// Like [update], but also returning the row as it was before the update, as
+/// `(old, new)`.
+///
+/// The row is locked with `SELECT ... FOR UPDATE` before updating, so this
+/// must be called with a transaction, returning
+/// [`Error::TransactionRequired`] otherwise.
+pub async fn update_returning_old<MC, X, U, E>(
+    executor: &mut X,
+    id: &<MC as DbBmc>::IdType,
+    data: U,
+) -> Result<(E, E)>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    U: Update,
+    E: Select,
+{
+    if !executor.is_transaction() {
+        return Err(Error::TransactionRequired { entity: MC::ENTITY });
+    }
+
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .columns(E::select_cols())
+        .and_where(Expr::col(MC::id_column()).eq(MC::id_to_value(id)))
+        .lock(LockType::Update);
+
+    exclude_soft_deleted::<MC>(&mut query);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let old = traced::<MC, _>(
+        DbBmcOp::Update,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_optional(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::Update,
+    })?
+    .ok_or_else(|| Error::EntityNotFound {
+        entity: MC::ENTITY,
+        id: id.to_string(),
+    })?;
+
+    let new = update::<MC, X, U, E>(executor, id, data).await?;
+
+    Ok((old, new))
+}
+
+/// Update a row in the model manager's table using the specified executor and
+/// id, with the values determined by the given function.
+///
+/// This is useful when which columns to set depends on runtime conditions.
+/// Like [update], this will return [`Error::EmptyUpdate`] if no values were
+/// set.
+pub async fn update_conditional<MC, X, F, E>(
+    executor: &mut X,
+    id: &<MC as DbBmc>::IdType,
+    f: F,
+) -> Result<E>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: FnOnce(&mut UpdateBuilder) -> Result<()>,
+    E: Select,
+{
+    let mut builder = UpdateBuilder::new();
+    f(&mut builder)?;
+
+    update::<MC, _, _, _>(executor, id, builder).await
+}
+
+/// Update every row in the model manager's table matching the given filter,
+/// returning the number of rows updated.
+///
+/// The updated rows are intentionally not returned, as there could be a great
+/// many of them.
+pub async fn update_all_where<MC, X, U, F>(executor: &mut X, data: U, filter: &F) -> Result<u64>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    U: Update,
+    F: Filter,
+{
+    let mut values = data.update_values();
+    if values.is_empty() {
+        return Err(Error::EmptyUpdateWhere { entity: MC::ENTITY });
+    }
+
+    update_timestamp::<MC>(&mut values);
+
+    let mut query = Query::update();
+
+    query
+        .table(MC::get_table_ref())
+        .values(values)
+        .cond_where(FilterCondition::<MC, _>::new(filter));
+
+    exclude_soft_deleted::<MC>(&mut query);
+
     let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
 
-    let entities: Vec<E> = sqlx::query_as_with(&sql, values)
-        .fetch_all(executor.as_executor())
-        .await
-        .context(SqlxSnafu)
-        .context(OperationSnafu {
-            entity: MC::ENTITY,
-            operation: DbBmcOp::ListPaginated,
-        })?;
+    let count = traced::<MC, _>(
+        DbBmcOp::UpdateWhere,
+        &sql,
+        sqlx::query_with(&sql, values).execute(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::UpdateWhere,
+    })?
+    .rows_affected();
 
-    Ok(Paginated::new(entities, filter.page_limit()))
+    Ok(count)
 }
 
-/// Update a row in the model manager's table using the specified executor, id, and data.
-pub async fn update<MC, X, U, E>(executor: &mut X, id: &<MC as DbBmc>::IdType, data: U) -> Result<E>
+/// Update every row in the model manager's table matching the given filter,
+/// returning the updated rows.
+///
+/// Prefer [update_all_where] if there could be a great many rows and you don't
+/// need them.
+pub async fn update_where<MC, X, U, F, E>(executor: &mut X, filter: &F, data: U) -> Result<Vec<E>>
 where
     MC: DbBmc,
     X: AsExecutor,
     U: Update,
+    F: Filter,
     E: Select,
 {
-    let values = data.update_values();
+    let mut values = data.update_values();
     if values.is_empty() {
-        return Err(Error::EmptyUpdate {
-            entity: MC::ENTITY,
-            id: id.to_string(),
-        });
+        return Err(Error::EmptyUpdateWhere { entity: MC::ENTITY });
     }
 
+    update_timestamp::<MC>(&mut values);
+
     let mut query = Query::update();
 
     query
         .table(MC::get_table_ref())
         .values(values)
-        .and_where(Expr::col(MC::id_column()).eq(MC::id_to_value(id)))
+        .cond_where(FilterCondition::<MC, _>::new(filter))
         .returning(Query::returning().columns(E::select_cols()));
 
+    exclude_soft_deleted::<MC>(&mut query);
+
     let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
 
-    let entity = sqlx::query_as_with(&sql, values)
-        .fetch_optional(executor.as_executor())
-        .await
-        .context(SqlxSnafu)
-        .context(OperationSnafu {
-            entity: MC::ENTITY,
-            operation: DbBmcOp::Update,
-        })?
-        .ok_or_else(|| Error::EntityNotFound {
-            entity: MC::ENTITY,
-            id: id.to_string(),
-        })?;
+    let entities: Vec<E> = traced::<MC, _>(
+        DbBmcOp::UpdateWhere,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::UpdateWhere,
+    })?;
 
-    Ok(entity)
+    Ok(entities)
 }
 
 /// Delete a row in the model manager's table, using the specified executor and id.
@@ -353,15 +2264,90 @@ where
 
     let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
 
-    let count = sqlx::query_with(&sql, values)
-        .execute(executor.as_executor())
-        .await
-        .context(SqlxSnafu)
-        .context(OperationSnafu {
+    let count = traced::<MC, _>(
+        DbBmcOp::Delete,
+        &sql,
+        sqlx::query_with(&sql, values).execute(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::Delete,
+    })?
+    .rows_affected();
+
+    if count == 0 {
+        return Err(Error::EntityNotFound {
             entity: MC::ENTITY,
-            operation: DbBmcOp::Delete,
-        })?
-        .rows_affected();
+            id: id.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Mark the row with the given id as deleted by setting
+/// [`DbBmc::SOFT_DELETE_COLUMN`] to `NOW()`, without removing it.
+///
+/// Returns [`Error::SoftDeleteUnsupported`] if the model has no soft delete
+/// column.
+pub async fn soft_delete<MC, X>(executor: &mut X, id: &<MC as DbBmc>::IdType) -> Result<()>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+{
+    set_soft_deleted::<MC, X>(executor, id, crate::expr::now(), DbBmcOp::SoftDelete).await
+}
+
+/// Undo a [soft_delete] by setting [`DbBmc::SOFT_DELETE_COLUMN`] to `NULL`.
+pub async fn restore<MC, X>(executor: &mut X, id: &<MC as DbBmc>::IdType) -> Result<()>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+{
+    set_soft_deleted::<MC, X>(
+        executor,
+        id,
+        SimpleExpr::Custom("NULL".into()),
+        DbBmcOp::Restore,
+    )
+    .await
+}
+
+async fn set_soft_deleted<MC, X>(
+    executor: &mut X,
+    id: &<MC as DbBmc>::IdType,
+    value: SimpleExpr,
+    operation: DbBmcOp,
+) -> Result<()>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+{
+    let col = MC::SOFT_DELETE_COLUMN.ok_or(Error::SoftDeleteUnsupported { entity: MC::ENTITY })?;
+
+    let mut query = Query::update();
+
+    query
+        .table(MC::get_table_ref())
+        .value(DynIden::new(col), value)
+        .and_where(Expr::col(MC::id_column()).eq(MC::id_to_value(id)));
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let count = traced::<MC, _>(
+        operation,
+        &sql,
+        sqlx::query_with(&sql, values).execute(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation,
+    })?
+    .rows_affected();
 
     if count == 0 {
         return Err(Error::EntityNotFound {
@@ -372,3 +2358,635 @@ where
 
     Ok(())
 }
+
+/// List only the rows that have been soft-deleted, ordered by id according to
+/// [`DbBmc::DEFAULT_ORDER`].
+///
+/// Returns [`Error::SoftDeleteUnsupported`] if the model has no soft delete
+/// column.
+pub async fn list_deleted<MC, X, E>(executor: &mut X) -> Result<Vec<E>>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    E: Select,
+{
+    let col = MC::SOFT_DELETE_COLUMN.ok_or(Error::SoftDeleteUnsupported { entity: MC::ENTITY })?;
+
+    let mut query = Query::select();
+
+    query
+        .from(MC::get_table_ref())
+        .columns(E::select_cols())
+        .and_where(Expr::col(DynIden::new(col)).is_not_null())
+        .order_by(MC::id_column(), MC::DEFAULT_ORDER);
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let entities: Vec<E> = traced::<MC, _>(
+        DbBmcOp::List,
+        &sql,
+        sqlx::query_as_with(&sql, values).fetch_all(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::List,
+    })?;
+
+    Ok(entities)
+}
+
+/// Delete all rows matching the filter, returning the number of rows deleted.
+///
+/// As a guard against accidentally deleting _every_ row, a filter that adds no
+/// conditions results in [`Error::EmptyFilter`]. Use [delete_all] if that's
+/// actually intended.
+///
+/// As with every use of a [`FilterCondition`], soft-deleted rows are not
+/// matched, so use [delete] or [delete_all] to remove those.
+pub async fn delete_where<MC, X, F>(executor: &mut X, filter: &F) -> Result<u64>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+    F: Filter,
+{
+    if !adds_conditions(filter) {
+        return Err(Error::EmptyFilter { entity: MC::ENTITY });
+    }
+
+    let mut query = Query::delete();
+
+    query
+        .from_table(MC::get_table_ref())
+        .cond_where(FilterCondition::<MC, _>::new(filter));
+
+    let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+    let count = traced::<MC, _>(
+        DbBmcOp::DeleteWhere,
+        &sql,
+        sqlx::query_with(&sql, values).execute(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::DeleteWhere,
+    })?
+    .rows_affected();
+
+    Ok(count)
+}
+
+/// Delete every row, returning the number of rows deleted.
+pub async fn delete_all<MC, X>(executor: &mut X) -> Result<u64>
+where
+    MC: DbBmc,
+    X: AsExecutor,
+{
+    let (sql, values) = Query::delete()
+        .from_table(MC::get_table_ref())
+        .build_sqlx(PostgresQueryBuilder);
+
+    let count = traced::<MC, _>(
+        DbBmcOp::DeleteAll,
+        &sql,
+        sqlx::query_with(&sql, values).execute(executor.as_executor()),
+    )
+    .await
+    .context(SqlxSnafu)
+    .context(OperationSnafu {
+        entity: MC::ENTITY,
+        operation: DbBmcOp::DeleteAll,
+    })?
+    .rows_affected();
+
+    Ok(count)
+}
+
+// whether the filter adds any conditions (or joins, etc.) to a query, ignoring
+// ordering and limits, which don't restrict the rows affected by a delete.
+fn adds_conditions<F>(filter: &F) -> bool
+where
+    F: Filter,
+{
+    if filter.is_noop() {
+        return false;
+    }
+
+    let unfiltered = Query::select().expr(Expr::val(1)).to_owned();
+
+    let mut filtered = unfiltered.clone();
+    filter.filter_query(&mut filtered);
+    filtered.clear_order_by().reset_limit().reset_offset();
+
+    filtered.to_string(PostgresQueryBuilder) != unfiltered.to_string(PostgresQueryBuilder)
+}
+
+/// Execute arbitrary SQL using the specified executor, returning the number of
+/// rows affected.
+///
+/// This exists for things like seeding test fixtures with raw SQL, and
+/// intentionally bypasses every guarantee the rest of this crate tries to
+/// provide. **Do not use this in production code.**
+pub async fn execute_raw<X>(executor: &mut X, sql: &str) -> Result<u64>
+where
+    X: AsExecutor,
+{
+    let res = sqlx::query(sql)
+        .execute(executor.as_executor())
+        .await
+        .context(ExecuteRawSnafu)?;
+
+    Ok(res.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_query::enum_def;
+
+    use super::*;
+
+    #[enum_def]
+    #[allow(unused)]
+    struct Dummy {
+        id: String,
+        name: String,
+        pages: i64,
+    }
+
+    // a controller for the dummies table, setting the given optional consts
+    macro_rules! dummy_bmc {
+        ($name:ident $(, $konst:ident = $value:literal)* $(,)?) => {
+            struct $name;
+
+            impl DbBmc for $name {
+                const ENTITY: &'static str = "dummy";
+                const TABLE: &'static str = "dummies";
+                $(const $konst: Option<&'static str> = Some($value);)*
+
+                type Error = Error;
+                type IdType = String;
+
+                fn id_column() -> DynIden {
+                    DummyIden::Id.into_iden()
+                }
+            }
+        };
+    }
+
+    dummy_bmc!(DummyBmc);
+    dummy_bmc!(SoftDummyBmc, SOFT_DELETE_COLUMN = "deleted_at");
+    dummy_bmc!(
+        TimestampedBmc,
+        CREATED_AT_COLUMN = "created_at",
+        UPDATED_AT_COLUMN = "updated_at",
+    );
+
+    #[derive(sqlx::FromRow)]
+    #[allow(unused)]
+    struct DummyRow {
+        id: String,
+    }
+
+    impl Select for DummyRow {
+        fn select_cols() -> Vec<DynIden> {
+            vec![DummyIden::Id.into_iden()]
+        }
+    }
+
+    impl Cursored for DummyRow {
+        type CursorType = String;
+
+        fn cursor_value(&self) -> Self::CursorType {
+            self.id.clone()
+        }
+
+        fn cursor_column() -> DynIden {
+            DummyIden::Id.into_iden()
+        }
+    }
+
+    struct DummyCreate {
+        name: String,
+        pages: Option<i64>,
+    }
+
+    impl Insert for DummyCreate {
+        fn insert_cols(&self) -> Vec<DynIden> {
+            let mut cols = vec![DummyIden::Name.into_iden()];
+            if self.pages.is_some() {
+                cols.push(DummyIden::Pages.into_iden());
+            }
+            cols
+        }
+
+        fn insert_vals(self) -> Vec<SimpleExpr> {
+            let mut vals = vec![self.name.into()];
+            if let Some(pages) = self.pages {
+                vals.push(pages.into());
+            }
+            vals
+        }
+    }
+
+    fn create(name: &str, pages: Option<i64>) -> DummyCreate {
+        DummyCreate {
+            name: name.into(),
+            pages,
+        }
+    }
+
+    struct NameFilter(&'static str);
+
+    struct NoopFilter;
+
+    impl Filter for NoopFilter {
+        fn is_noop(&self) -> bool {
+            true
+        }
+    }
+
+    impl Filter for NameFilter {
+        fn filter_query(&self, query: &mut SelectStatement) {
+            query.and_where(Expr::col(DummyIden::Name).eq(self.0));
+        }
+    }
+
+    struct DummyPages;
+
+    impl Filter for DummyPages {
+        fn is_noop(&self) -> bool {
+            true
+        }
+    }
+
+    impl CursoredFilter for DummyPages {
+        type Entity = DummyRow;
+
+        fn cursor(&self) -> Option<String> {
+            None
+        }
+
+        fn set_cursor(&mut self, _cursor: String) {}
+
+        fn page_limit(&self) -> usize {
+            10
+        }
+    }
+
+    #[tokio::test]
+    async fn listing_in_chunks_of_zero() {
+        // lazy pools don't connect until used, so no server is needed
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .connect_lazy("postgres://user@localhost/books")
+            .expect("valid url");
+        let mut mm = crate::DbModelManager::from(pool);
+
+        let res =
+            list_in_chunks::<DummyBmc, _, _, DummyRow, _>(&mut mm, DummyPages, 0, async |_| Ok(()))
+                .await;
+
+        assert!(matches!(
+            res,
+            Err(Error::InvalidChunkSize { entity: "dummy" })
+        ));
+    }
+
+    #[test]
+    fn dyn_filters() {
+        let filters: Vec<Box<dyn Filter>> = vec![Box::new(NameFilter("foo")), Box::new(NoopFilter)];
+
+        let mut query = Query::select();
+        query.column(DummyIden::Id).from(DummyIden::Table);
+        for filter in filters.iter() {
+            query.apply_filter(filter);
+        }
+
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"SELECT "id" FROM "dummy" WHERE "name" = 'foo'"#
+        );
+    }
+
+    #[test]
+    fn insert_many_builds_single_statement() {
+        let query = insert_many_statement::<DummyBmc, _>(vec![
+            create("foo", Some(1)),
+            create("bar", Some(2)),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"INSERT INTO "dummies" ("name", "pages") VALUES ('foo', 1), ('bar', 2)"#
+        );
+    }
+
+    #[test]
+    fn insert_many_rejects_empty_and_inconsistent_data() {
+        assert!(matches!(
+            insert_many_statement::<DummyBmc, DummyCreate>(vec![]),
+            Err(Error::EmptyInsert { entity: "dummy" })
+        ));
+
+        assert!(matches!(
+            insert_many_statement::<DummyBmc, _>(vec![create("foo", Some(1)), create("bar", None)]),
+            Err(Error::InconsistentInsert { entity: "dummy" })
+        ));
+    }
+
+    #[test]
+    fn db_bmc_error_display() {
+        let err = DbBmcError::Operation {
+            entity: "book",
+            operation: DbBmcOp::Create,
+            source: OpError::Sqlx {
+                source: sqlx::Error::RowNotFound,
+            },
+        };
+
+        assert_eq!(err.to_string(), "[ENTITY=book, OP=CREATE]: ");
+    }
+
+    #[test]
+    fn filter_condition() {
+        let query = Query::delete()
+            .from_table(DummyBmc::get_table_ref())
+            .cond_where(FilterCondition::<DummyBmc, _>::new(NameFilter("foo")))
+            .to_owned();
+
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"DELETE FROM "dummies" WHERE "id" IN (SELECT "id" FROM "dummies" WHERE "name" = 'foo')"#
+        );
+    }
+
+    #[test]
+    fn noop_filter_condition() {
+        let query = Query::delete()
+            .from_table(DummyBmc::get_table_ref())
+            .cond_where(FilterCondition::<DummyBmc, _>::new(NoopFilter))
+            .to_owned();
+
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"DELETE FROM "dummies" WHERE TRUE"#
+        );
+    }
+
+    #[test]
+    fn bounding_stable_cursors() {
+        let sql = |cursor_cols: Vec<DynIden>, cursor: SimpleExpr, id: Option<i64>| {
+            let (lhs, rhs) = stable_cursor_bound(
+                cursor_cols,
+                cursor,
+                id.map(|id| (DummyIden::Id.into_iden(), id.into())),
+            );
+
+            Query::select()
+                .column(DummyIden::Id)
+                .from(DummyIden::Table)
+                .and_where(lhs.gt(rhs))
+                .to_string(PostgresQueryBuilder)
+        };
+
+        assert_eq!(
+            sql(vec![DummyIden::Pages.into_iden()], 100.into(), None),
+            r#"SELECT "id" FROM "dummy" WHERE "pages" > 100"#
+        );
+        assert_eq!(
+            sql(vec![DummyIden::Pages.into_iden()], 100.into(), Some(5)),
+            r#"SELECT "id" FROM "dummy" WHERE ("pages", "id") > (100, 5)"#
+        );
+
+        let compound = || {
+            (
+                vec![DummyIden::Pages.into_iden(), DummyIden::Name.into_iden()],
+                crate::CompoundCursor(100, "dune").into(),
+            )
+        };
+
+        let (cols, cursor) = compound();
+        assert_eq!(
+            sql(cols, cursor, None),
+            r#"SELECT "id" FROM "dummy" WHERE ("pages", "name") > (100, 'dune')"#
+        );
+
+        let (cols, cursor) = compound();
+        assert_eq!(
+            sql(cols, cursor, Some(5)),
+            r#"SELECT "id" FROM "dummy" WHERE ("pages", "name", "id") > (100, 'dune', 5)"#
+        );
+    }
+
+    #[test]
+    fn qualifying_and_aliasing_tables() {
+        dummy_bmc!(AuditDummyBmc, TABLE_SCHEMA = "audit");
+        dummy_bmc!(AliasedDummyBmc, TABLE_ALIAS = "d");
+        dummy_bmc!(
+            AliasedAuditDummyBmc,
+            TABLE_SCHEMA = "audit",
+            TABLE_ALIAS = "d",
+        );
+
+        let sql = |table: TableRef| {
+            Query::select()
+                .column(DummyIden::Id)
+                .from(table)
+                .to_string(PostgresQueryBuilder)
+        };
+
+        assert_eq!(
+            sql(DummyBmc::get_table_ref()),
+            r#"SELECT "id" FROM "dummies""#
+        );
+        assert_eq!(
+            sql(AuditDummyBmc::get_table_ref()),
+            r#"SELECT "id" FROM "audit"."dummies""#
+        );
+        assert_eq!(
+            sql(AliasedDummyBmc::get_table_ref()),
+            r#"SELECT "id" FROM "dummies" AS "d""#
+        );
+        assert_eq!(
+            sql(AliasedAuditDummyBmc::get_table_ref()),
+            r#"SELECT "id" FROM "audit"."dummies" AS "d""#
+        );
+    }
+
+    #[test]
+    fn counting_without_soft_deleted_rows() {
+        assert_eq!(
+            count_statement::<DummyBmc, _>(&()).to_string(PostgresQueryBuilder),
+            r#"SELECT COUNT("id") FROM "dummies""#
+        );
+        assert_eq!(
+            count_statement::<SoftDummyBmc, _>(&()).to_string(PostgresQueryBuilder),
+            r#"SELECT COUNT("id") FROM "dummies" WHERE "deleted_at" IS NULL"#
+        );
+        assert_eq!(
+            count_statement::<SoftDummyBmc, _>(&NameFilter("foo")).to_string(PostgresQueryBuilder),
+            r#"SELECT COUNT("id") FROM "dummies" WHERE "name" = 'foo' AND "deleted_at" IS NULL"#
+        );
+    }
+
+    #[test]
+    fn paginating_without_soft_deleted_rows() {
+        let sql = |cursor: Option<&str>| {
+            list_paginated_statement::<SoftDummyBmc, _, DummyRow, _>(
+                &NameFilter("foo"),
+                cursor,
+                10,
+                None,
+                sea_query::Order::Asc,
+                true,
+            )
+            .to_string(PostgresQueryBuilder)
+        };
+
+        assert_eq!(
+            sql(None),
+            r#"SELECT "id" FROM "dummies" WHERE "name" = 'foo' AND "deleted_at" IS NULL ORDER BY "id" ASC NULLS LAST LIMIT 10"#
+        );
+        assert_eq!(
+            sql(Some("abc")),
+            r#"SELECT "id" FROM "dummies" WHERE "id" > 'abc' AND "name" = 'foo' AND "deleted_at" IS NULL ORDER BY "id" ASC NULLS LAST LIMIT 10"#
+        );
+    }
+
+    #[test]
+    fn filter_conditions_exclude_soft_deleted_rows() {
+        let mut query = Query::update();
+        query
+            .table(SoftDummyBmc::get_table_ref())
+            .value(DummyIden::Pages, 1)
+            .cond_where(FilterCondition::<SoftDummyBmc, _>::new(&NameFilter("foo")));
+
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"UPDATE "dummies" SET "pages" = 1 WHERE "id" IN (SELECT "id" FROM "dummies" WHERE "name" = 'foo' AND "deleted_at" IS NULL)"#
+        );
+    }
+
+    #[test]
+    fn excluding_soft_deleted_rows() {
+        let mut query = Query::select();
+        query.column(DummyIden::Id).from(DummyIden::Table);
+        exclude_soft_deleted::<DummyBmc>(&mut query);
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"SELECT "id" FROM "dummy""#
+        );
+
+        exclude_soft_deleted::<SoftDummyBmc>(&mut query);
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"SELECT "id" FROM "dummy" WHERE "deleted_at" IS NULL"#
+        );
+    }
+
+    #[test]
+    fn setting_timestamps() {
+        let mut cols = vec![DummyIden::Name.into_iden(), DynIden::new("updated_at")];
+        let mut vals = vec!["foo".into(), Expr::cust("clock_timestamp()")];
+        insert_timestamps::<TimestampedBmc>(&mut cols, &mut vals);
+
+        let cols: Vec<_> = cols.iter().map(|c| c.to_string()).collect();
+        assert_eq!(cols, ["name", "updated_at", "created_at"]);
+        assert_eq!(vals[2], crate::expr::now());
+
+        let mut values = vec![(DummyIden::Name.into_iden(), "foo".into())];
+        update_timestamp::<DummyBmc>(&mut values);
+        assert_eq!(values.len(), 1);
+
+        update_timestamp::<TimestampedBmc>(&mut values);
+        assert_eq!(values[1].0.to_string(), "updated_at");
+        assert_eq!(values[1].1, crate::expr::now());
+    }
+
+    #[test]
+    fn upsert_requires_conflict_columns_and_values() {
+        let mut update = UpdateBuilder::new();
+        update.set(DummyIden::Name, "bar");
+
+        assert!(matches!(
+            upsert_statement::<DummyBmc, _, _>(create("foo", Some(1)), &[], update.clone()),
+            Err(Error::EmptyConflictColumns { entity: "dummy" })
+        ));
+
+        assert!(matches!(
+            upsert_statement::<DummyBmc, _, _>(
+                create("foo", Some(1)),
+                &[DummyIden::Name.into_iden()],
+                ()
+            ),
+            Err(Error::EmptyUpsert { entity: "dummy" })
+        ));
+    }
+
+    #[test]
+    fn upsert_sets_timestamps() {
+        let mut update = UpdateBuilder::new();
+        update.set(DummyIden::Pages, 2);
+
+        let query = upsert_statement::<TimestampedBmc, _, _>(
+            create("foo", Some(1)),
+            &[DummyIden::Name.into_iden()],
+            update,
+        )
+        .expect("valid upsert");
+
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"INSERT INTO "dummies" ("name", "pages", "created_at", "updated_at") VALUES ('foo', 1, NOW(), NOW()) ON CONFLICT ("name") DO UPDATE SET "pages" = 2, "updated_at" = NOW()"#
+        );
+    }
+
+    #[test]
+    fn rejecting_duplicate_ids() {
+        assert!(ensure_unique_ids::<DummyBmc>(&["a".into(), "b".into()]).is_ok());
+
+        let err = ensure_unique_ids::<DummyBmc>(&["a".into(), "b".into(), "a".into()])
+            .expect_err("duplicate id");
+        assert!(matches!(err, Error::DuplicateId { entity: "dummy", ref id } if id == "a"));
+    }
+
+    #[test]
+    fn insert_many_sets_timestamps() {
+        let query = insert_many_statement::<TimestampedBmc, _>(vec![
+            create("foo", Some(1)),
+            create("bar", Some(2)),
+        ])
+        .expect("consistent columns");
+
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"INSERT INTO "dummies" ("name", "pages", "created_at", "updated_at") VALUES ('foo', 1, NOW(), NOW()), ('bar', 2, NOW(), NOW())"#
+        );
+    }
+
+    #[test]
+    fn unit_update_is_empty() {
+        assert!(().update_values().is_empty());
+    }
+
+    #[test]
+    fn detecting_filter_conditions() {
+        struct OrderOnly;
+
+        impl Filter for OrderOnly {
+            fn filter_query(&self, query: &mut SelectStatement) {
+                query
+                    .order_by(DummyIden::Name, sea_query::Order::Asc)
+                    .limit(5);
+            }
+        }
+
+        assert!(!adds_conditions(&NoopFilter));
+        assert!(!adds_conditions(&()));
+        assert!(!adds_conditions(&OrderOnly));
+        assert!(adds_conditions(&NameFilter("foo")));
+    }
+}
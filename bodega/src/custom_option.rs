@@ -37,3 +37,88 @@ where
         value.into_expr()
     }
 }
+
+/// Like [`CustomOption`], but for nullable arrays, where `None` is `NULL` and
+/// `Some(vec![])` is an empty array.
+///
+/// This is needed as sea-query has no conversion from a `Vec` of an arbitrary
+/// `Into<SimpleExpr>` type (like a [`store_enum`](crate::store_enum)) into an
+/// array expression.
+pub struct CustomVecOption<T>(pub Option<Vec<T>>);
+
+impl<T> From<Option<Vec<T>>> for CustomVecOption<T>
+where
+    T: Into<sea_query::SimpleExpr>,
+{
+    fn from(value: Option<Vec<T>>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> CustomVecOption<T>
+where
+    T: Into<sea_query::SimpleExpr>,
+{
+    pub fn into_expr(self) -> sea_query::SimpleExpr {
+        match self.0 {
+            None => sea_query::SimpleExpr::Custom("NULL".into()),
+            // an untyped literal, so postgres can infer the element type from
+            // the column
+            Some(values) if values.is_empty() => sea_query::Expr::cust("'{}'"),
+            Some(values) => {
+                // the brackets are passed as exprs, as sea-query would
+                // otherwise treat `[...]` as a quoted identifier and skip the
+                // placeholders within it.
+                let len = values.len();
+                let placeholders = (2..len + 2)
+                    .map(|i| format!("${i}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let exprs = std::iter::once(sea_query::Expr::cust("ARRAY["))
+                    .chain(values.into_iter().map(Into::into))
+                    .chain(std::iter::once(sea_query::Expr::cust("]")));
+
+                sea_query::Expr::cust_with_exprs(format!("$1 {placeholders} ${}", len + 2), exprs)
+            }
+        }
+    }
+}
+
+impl<T> From<CustomVecOption<T>> for sea_query::SimpleExpr
+where
+    T: Into<sea_query::SimpleExpr>,
+{
+    fn from(value: CustomVecOption<T>) -> Self {
+        value.into_expr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_query::{Alias, PostgresQueryBuilder, Query};
+
+    use super::*;
+
+    fn render(expr: sea_query::SimpleExpr) -> String {
+        Query::select().expr(expr).to_string(PostgresQueryBuilder)
+    }
+
+    #[test]
+    fn nullable_arrays() {
+        assert_eq!(render(CustomVecOption::<i32>(None).into()), "SELECT NULL");
+        assert_eq!(
+            render(CustomVecOption::<i32>(Some(vec![])).into()),
+            "SELECT '{}'"
+        );
+        assert_eq!(
+            render(CustomVecOption(Some(vec![1, 2])).into()),
+            "SELECT ARRAY[ 1, 2 ]"
+        );
+
+        let cast = sea_query::Expr::val("mystery").as_enum(Alias::new("genre"));
+        assert_eq!(
+            render(CustomVecOption(Some(vec![cast])).into()),
+            r#"SELECT ARRAY[ CAST('mystery' AS "genre") ]"#
+        );
+    }
+}
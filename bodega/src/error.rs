@@ -1,4 +1,5 @@
 use snafu::Snafu;
+use sqlx::postgres::PgDatabaseError;
 
 use crate::{DbBmcError, DbModelManagerError, OpError};
 
@@ -6,18 +7,60 @@ pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug, Snafu)]
 pub enum Error {
+    #[snafu(display("Attempted to insert no rows for '{entity}'"))]
+    EmptyInsert { entity: &'static str },
+
+    #[snafu(display("Attempted to insert rows with differing columns for '{entity}'"))]
+    InconsistentInsert { entity: &'static str },
+
     #[snafu(display("Attempted empty update for '{entity}' with id '{id}'"))]
     EmptyUpdate { entity: &'static str, id: String },
 
+    #[snafu(display("Attempted empty update for '{entity}' matching filter"))]
+    EmptyUpdateWhere { entity: &'static str },
+
+    #[snafu(display("Attempted to delete every '{entity}' with a filter adding no conditions"))]
+    EmptyFilter { entity: &'static str },
+
+    #[snafu(display("Attempted upsert for '{entity}' with no values to update"))]
+    EmptyUpsert { entity: &'static str },
+
+    #[snafu(display("Attempted upsert for '{entity}' with no conflict columns"))]
+    EmptyConflictColumns { entity: &'static str },
+
     #[snafu(display("Could not find '{entity}' with id '{id}'"))]
     EntityNotFound { entity: &'static str, id: String },
 
-    #[snafu(display("Failed to create DB pool: {message}"))]
-    FailedToCreateDBPool { message: String },
+    #[snafu(display("Requested '{entity}' with id '{id}' more than once"))]
+    DuplicateId { entity: &'static str, id: String },
+
+    #[snafu(display("Could not find '{entity}' matching filter"))]
+    EntityNotFoundWhere { entity: &'static str },
+
+    #[snafu(display("'{entity}' does not support soft deletes"))]
+    SoftDeleteUnsupported { entity: &'static str },
+
+    #[snafu(display("Operation on '{entity}' must be performed in a transaction"))]
+    TransactionRequired { entity: &'static str },
+
+    #[snafu(display("Invalid chunk size 0 for '{entity}'"))]
+    InvalidChunkSize { entity: &'static str },
+
+    #[snafu(display("Invalid column name '{name}'"))]
+    InvalidColumnName { name: String },
+
+    #[snafu(display("Failed to create DB pool for '{sanitized_url}': {message}"))]
+    FailedToCreateDBPool {
+        message: String,
+        sanitized_url: String,
+    },
 
     #[snafu(display("DbBmc error: "))]
     DbBmc { source: DbBmcError },
 
+    #[snafu(display("Error executing raw SQL: "), visibility(pub(crate)))]
+    ExecuteRaw { source: sqlx::Error },
+
     #[snafu(display("ModelManagr error: "))]
     ModelManager { source: DbModelManagerError },
 
@@ -36,21 +79,57 @@ impl Error {
     ///
     /// This is a convenience proxy for the constraint on the underlying [`sqlx::Error`].
     pub fn constraint(&self) -> Option<&str> {
-        match self {
+        self.database_error().and_then(|e| e.constraint())
+    }
+
+    /// If applicable, returns the SQLSTATE code of the error, like `"23503"`
+    /// for a foreign key violation.
+    ///
+    /// This is a convenience proxy for the code on the underlying [`sqlx::Error`].
+    pub fn violation_code(&self) -> Option<&str> {
+        self.database_error()
+            .and_then(|e| e.try_downcast_ref::<PgDatabaseError>())
+            .map(|e| e.code())
+    }
+
+    /// Whether this error indicates the entity does not exist, as with
+    /// [`Error::EntityNotFound`] or [`Error::EntityNotFoundWhere`].
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Error::EntityNotFound { .. } | Error::EntityNotFoundWhere { .. }
+        )
+    }
+
+    /// Whether this error was caused by a unique constraint violation
+    /// (`23505`), e.g. inserting a duplicate key.
+    pub fn is_conflict(&self) -> bool {
+        self.violation_code() == Some("23505")
+    }
+
+    /// Whether this is an [`Error::TransactionSerialization`], meaning the
+    /// transaction may succeed if retried.
+    pub fn is_serialization(&self) -> bool {
+        matches!(self, Error::TransactionSerialization { .. })
+    }
+
+    // the underlying database error, if any, from the operation or model
+    // manager.
+    fn database_error(&self) -> Option<&dyn sqlx::error::DatabaseError> {
+        let source = match self {
             Error::DbBmc {
                 source:
                     DbBmcError::Operation {
-                        source:
-                            OpError::Sqlx {
-                                source: sqlx::Error::Database(ref e),
-                            },
+                        source: OpError::Sqlx { source },
                         ..
                     },
-            } => e.constraint(),
-            Error::ModelManager { source } => match source.source() {
-                sqlx::Error::Database(ref e) => e.constraint(),
-                _ => None,
-            },
+            } => source,
+            Error::ModelManager { source } => source.source(),
+            _ => return None,
+        };
+
+        match source {
+            sqlx::Error::Database(ref e) => Some(e.as_ref()),
             _ => None,
         }
     }
@@ -96,3 +175,30 @@ impl From<DbModelManagerError> for Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifying_errors() {
+        let not_found = Error::EntityNotFound {
+            entity: "book",
+            id: "1".into(),
+        };
+        assert!(not_found.is_not_found());
+        assert!(Error::EntityNotFoundWhere { entity: "book" }.is_not_found());
+        assert!(!not_found.is_conflict());
+        assert!(!not_found.is_serialization());
+
+        let pool_closed = Error::ModelManager {
+            source: DbModelManagerError::Connectivity {
+                source: sqlx::Error::PoolClosed,
+            },
+        };
+        assert!(!pool_closed.is_not_found());
+        assert!(!pool_closed.is_conflict());
+        assert_eq!(pool_closed.constraint(), None);
+        assert_eq!(pool_closed.violation_code(), None);
+    }
+}
@@ -0,0 +1,382 @@
+//! Helpers for building `sea_query` expressions outside of the provided
+//! operations.
+use sea_query::{
+    extension::postgres::PgFunc, Alias, ArrayType, DynIden, Expr, Func, IntoIden, NullOrdering,
+    Order, SelectStatement, SimpleExpr, Value,
+};
+
+use crate::{Error, Filter, Result, Select};
+
+/// Get a column reference from the given name.
+///
+/// The name is used verbatim, so be careful using this with values supplied
+/// by users. Prefer [`validate_column_name`] in those cases.
+pub fn iden_from_str(col_name: &str) -> DynIden {
+    Alias::new(col_name).into_iden()
+}
+
+/// Get a column reference from the given name, provided the name is one of
+/// the `allowed` names.
+///
+/// Useful for things like allowing a caller to specify a column to sort by.
+pub fn validate_column_name(name: &str, allowed: &[&str]) -> Result<DynIden> {
+    if allowed.contains(&name) {
+        Ok(iden_from_str(name))
+    } else {
+        Err(Error::InvalidColumnName {
+            name: name.to_string(),
+        })
+    }
+}
+
+/// Restrict `col` to any of the given values.
+///
+/// If the values are all plain values of a type postgres supports in arrays,
+/// this binds them as a single array with `col = ANY($1)`, which keeps the
+/// statement the same regardless of the number of values. Otherwise, this
+/// falls back to `col IN ($1, $2, ...)`.
+pub fn eq_any<C>(col: C, values: Vec<SimpleExpr>) -> SimpleExpr
+where
+    C: IntoIden,
+{
+    let col = Expr::col(col.into_iden());
+
+    let array_type = match values.first() {
+        Some(SimpleExpr::Value(v)) => array_type(v),
+        _ => None,
+    };
+
+    let Some(array_type) = array_type else {
+        return col.is_in(values);
+    };
+
+    let mut array = Vec::with_capacity(values.len());
+    for val in values.iter() {
+        match val {
+            SimpleExpr::Value(v) if self::array_type(v).as_ref() == Some(&array_type) => {
+                array.push(v.clone())
+            }
+            _ => return col.is_in(values),
+        }
+    }
+
+    col.eq(PgFunc::any(Expr::val(Value::Array(
+        array_type,
+        Some(Box::new(array)),
+    ))))
+}
+
+fn array_type(value: &Value) -> Option<ArrayType> {
+    match value {
+        Value::Bool(Some(_)) => Some(ArrayType::Bool),
+        Value::TinyInt(Some(_)) => Some(ArrayType::TinyInt),
+        Value::SmallInt(Some(_)) => Some(ArrayType::SmallInt),
+        Value::Int(Some(_)) => Some(ArrayType::Int),
+        Value::BigInt(Some(_)) => Some(ArrayType::BigInt),
+        Value::String(Some(_)) => Some(ArrayType::String),
+        Value::Uuid(Some(_)) => Some(ArrayType::Uuid),
+        Value::ChronoDateTimeUtc(Some(_)) => Some(ArrayType::ChronoDateTimeUtc),
+        _ => None,
+    }
+}
+
+/// `NOW()`, the start time of the current transaction.
+///
+/// Postgres' `clock_timestamp()` gives the time of the current statement instead.
+pub fn now() -> SimpleExpr {
+    Expr::cust("NOW()")
+}
+
+/// `CURRENT_TIMESTAMP`, equivalent to [`now`].
+pub fn current_timestamp() -> SimpleExpr {
+    Expr::current_timestamp().into()
+}
+
+/// `gen_random_uuid()`, a random (v4) uuid generated by the database.
+pub fn gen_random_uuid() -> SimpleExpr {
+    Expr::cust("gen_random_uuid()")
+}
+
+/// `MD5(col)`, the md5 hash of the value of `col` as a hex string.
+pub fn md5<C>(col: C) -> SimpleExpr
+where
+    C: IntoIden,
+{
+    Func::md5(Expr::col(col.into_iden())).into()
+}
+
+/// Extends [`SelectStatement`] with the query patterns used by the provided
+/// operations, for use when writing custom queries.
+pub trait SelectStatementExt {
+    /// Order by the given cursor column, restricting the results to those
+    /// after the cursor value, if present.
+    ///
+    /// "After" is relative to `order`, so a descending order will select
+    /// values less than the cursor.
+    fn paginate_cursor<C, V>(
+        &mut self,
+        cursor_col: C,
+        cursor_val: Option<V>,
+        order: Order,
+    ) -> &mut Self
+    where
+        C: IntoIden,
+        V: Into<SimpleExpr>;
+
+    /// Like [`SelectStatementExt::paginate_cursor`], but explicitly ordering
+    /// `NULL`s in the cursor column first or last.
+    fn paginate_cursor_with_nulls<C, V>(
+        &mut self,
+        cursor_col: C,
+        cursor_val: Option<V>,
+        order: Order,
+        nulls: NullOrdering,
+    ) -> &mut Self
+    where
+        C: IntoIden,
+        V: Into<SimpleExpr>;
+
+    /// Like [`SelectStatementExt::paginate_cursor_with_nulls`], but for a
+    /// cursor over any number of columns, as from [`Cursored::cursor_columns`].
+    ///
+    /// With more than one column, the columns are compared with the cursor as
+    /// a row value, like `(created_at, id) > ($1, $2)`, and ordered in turn.
+    ///
+    /// [`Cursored::cursor_columns`]: crate::Cursored::cursor_columns
+    fn paginate_cursor_columns_with_nulls<V>(
+        &mut self,
+        cursor_cols: Vec<DynIden>,
+        cursor_val: Option<V>,
+        order: Order,
+        nulls: NullOrdering,
+    ) -> &mut Self
+    where
+        V: Into<SimpleExpr>;
+
+    /// Add the conditions from the given filter.
+    fn apply_filter<F>(&mut self, filter: &F) -> &mut Self
+    where
+        F: Filter;
+
+    /// Select the columns required to construct the given model.
+    fn select_model<E>(&mut self) -> &mut Self
+    where
+        E: Select;
+}
+
+impl SelectStatementExt for SelectStatement {
+    fn paginate_cursor<C, V>(
+        &mut self,
+        cursor_col: C,
+        cursor_val: Option<V>,
+        order: Order,
+    ) -> &mut Self
+    where
+        C: IntoIden,
+        V: Into<SimpleExpr>,
+    {
+        let cursor_col = cursor_col.into_iden();
+
+        after_cursor(self, Expr::col(cursor_col.clone()), cursor_val, &order);
+
+        self.order_by(cursor_col, order)
+    }
+
+    fn paginate_cursor_with_nulls<C, V>(
+        &mut self,
+        cursor_col: C,
+        cursor_val: Option<V>,
+        order: Order,
+        nulls: NullOrdering,
+    ) -> &mut Self
+    where
+        C: IntoIden,
+        V: Into<SimpleExpr>,
+    {
+        let cursor_col = cursor_col.into_iden();
+
+        after_cursor(self, Expr::col(cursor_col.clone()), cursor_val, &order);
+
+        self.order_by_with_nulls(cursor_col, order, nulls)
+    }
+
+    fn paginate_cursor_columns_with_nulls<V>(
+        &mut self,
+        cursor_cols: Vec<DynIden>,
+        cursor_val: Option<V>,
+        order: Order,
+        nulls: NullOrdering,
+    ) -> &mut Self
+    where
+        V: Into<SimpleExpr>,
+    {
+        if let [cursor_col] = cursor_cols.as_slice() {
+            return self.paginate_cursor_with_nulls(cursor_col.clone(), cursor_val, order, nulls);
+        }
+
+        let lhs = Expr::tuple(cursor_cols.iter().map(|c| Expr::col(c.clone()).into()));
+        after_cursor(self, lhs, cursor_val, &order);
+
+        for cursor_col in cursor_cols {
+            self.order_by_with_nulls(cursor_col, order.clone(), nulls);
+        }
+
+        self
+    }
+
+    fn apply_filter<F>(&mut self, filter: &F) -> &mut Self
+    where
+        F: Filter,
+    {
+        if !filter.is_noop() {
+            filter.filter_query(self);
+        }
+
+        self
+    }
+
+    fn select_model<E>(&mut self) -> &mut Self
+    where
+        E: Select,
+    {
+        self.columns(E::select_cols())
+    }
+}
+
+// restrict the results to those after the cursor, relative to the order.
+fn after_cursor<V>(query: &mut SelectStatement, lhs: Expr, cursor_val: Option<V>, order: &Order)
+where
+    V: Into<SimpleExpr>,
+{
+    if let Some(cursor) = cursor_val {
+        if *order == Order::Asc {
+            query.and_where(lhs.gt(cursor));
+        } else {
+            query.and_where(lhs.lt(cursor));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_query::{PostgresQueryBuilder, Query};
+
+    use super::*;
+
+    #[test]
+    fn validating_column_names() {
+        let allowed = ["title", "author"];
+
+        let col = validate_column_name("title", &allowed).unwrap();
+        assert_eq!(col.to_string(), "title");
+
+        assert!(matches!(
+            validate_column_name("pages; DROP TABLE books", &allowed),
+            Err(Error::InvalidColumnName { .. })
+        ));
+    }
+
+    #[test]
+    fn paginating_by_cursor_with_nulls() {
+        let sql = Query::select()
+            .column(iden_from_str("id"))
+            .from(iden_from_str("books"))
+            .paginate_cursor_with_nulls(
+                iden_from_str("id"),
+                Some(5),
+                Order::Desc,
+                NullOrdering::Last,
+            )
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            sql,
+            r#"SELECT "id" FROM "books" WHERE "id" < 5 ORDER BY "id" DESC NULLS LAST"#
+        );
+    }
+
+    #[test]
+    fn paginating_by_compound_cursor() {
+        let sql = Query::select()
+            .column(iden_from_str("id"))
+            .from(iden_from_str("books"))
+            .paginate_cursor_columns_with_nulls(
+                vec![iden_from_str("pages"), iden_from_str("id")],
+                Some(crate::CompoundCursor(100, 5)),
+                Order::Asc,
+                NullOrdering::Last,
+            )
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            sql,
+            r#"SELECT "id" FROM "books" WHERE ("pages", "id") > (100, 5) ORDER BY "pages" ASC NULLS LAST, "id" ASC NULLS LAST"#
+        );
+    }
+
+    #[test]
+    fn paginating_by_cursor() {
+        let sql = Query::select()
+            .column(iden_from_str("id"))
+            .from(iden_from_str("books"))
+            .paginate_cursor(iden_from_str("id"), Some(5), Order::Desc)
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            sql,
+            r#"SELECT "id" FROM "books" WHERE "id" < 5 ORDER BY "id" DESC"#
+        );
+
+        let sql = Query::select()
+            .column(iden_from_str("id"))
+            .from(iden_from_str("books"))
+            .paginate_cursor(iden_from_str("id"), None::<i64>, Order::Asc)
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(sql, r#"SELECT "id" FROM "books" ORDER BY "id" ASC"#);
+    }
+
+    #[test]
+    fn eq_any_of_values() {
+        let sql = Query::select()
+            .column(iden_from_str("id"))
+            .from(iden_from_str("books"))
+            .and_where(eq_any(iden_from_str("id"), vec![1i64.into(), 2i64.into()]))
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            sql,
+            r#"SELECT "id" FROM "books" WHERE "id" = ANY(ARRAY [1,2])"#
+        );
+
+        let sql = Query::select()
+            .column(iden_from_str("id"))
+            .from(iden_from_str("books"))
+            .and_where(eq_any(
+                iden_from_str("id"),
+                vec![1i64.into(), Expr::val(2i64).cast_as(Alias::new("bigint"))],
+            ))
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            sql,
+            r#"SELECT "id" FROM "books" WHERE "id" IN (1, CAST(2 AS bigint))"#
+        );
+    }
+
+    #[test]
+    fn server_side_functions() {
+        let sql = Query::select()
+            .expr(now())
+            .expr(current_timestamp())
+            .expr(gen_random_uuid())
+            .expr(md5(iden_from_str("title")))
+            .from(iden_from_str("books"))
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            sql,
+            r#"SELECT NOW(), CURRENT_TIMESTAMP, gen_random_uuid(), MD5("title") FROM "books""#
+        );
+    }
+}
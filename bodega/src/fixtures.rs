@@ -0,0 +1,179 @@
+//! Helpers for seeding databases with known rows in tests.
+use std::marker::PhantomData;
+
+use crate::{create_many, AsExecutor, DbBmc, Insert, Result, Select};
+
+/// A set of rows to insert into the table of the model manager `MC`.
+#[derive(Debug, Clone)]
+pub struct Fixture<MC, I> {
+    rows: Vec<I>,
+    _controller: PhantomData<fn() -> MC>,
+}
+
+impl<MC, I> Fixture<MC, I>
+where
+    MC: DbBmc,
+    I: Insert + Clone,
+{
+    pub fn new(rows: Vec<I>) -> Self {
+        Self {
+            rows,
+            _controller: PhantomData,
+        }
+    }
+
+    pub fn rows(&self) -> &[I] {
+        &self.rows
+    }
+
+    /// Insert the rows of this fixture in a single statement, returning the
+    /// created rows.
+    ///
+    /// Applying an empty fixture is a no-op.
+    pub async fn apply<X, E>(&self, executor: &mut X) -> Result<Vec<E>>
+    where
+        X: AsExecutor,
+        E: Select,
+    {
+        if self.rows.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        create_many::<MC, X, I, E>(executor, self.rows.clone()).await
+    }
+}
+
+/// Builds a [`Fixture`] from individual rows and generated ones.
+#[derive(Debug, Clone)]
+pub struct FixtureBuilder<I> {
+    rows: Vec<I>,
+}
+
+impl<I> Default for FixtureBuilder<I> {
+    fn default() -> Self {
+        Self { rows: Vec::new() }
+    }
+}
+
+impl<I> FixtureBuilder<I>
+where
+    I: Insert + Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a single row.
+    pub fn row(mut self, row: I) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Add `count` rows, each produced by calling `f`.
+    pub fn generate<F>(mut self, count: usize, f: F) -> Self
+    where
+        F: Fn() -> I,
+    {
+        self.rows.extend((0..count).map(|_| f()));
+        self
+    }
+
+    pub fn build<MC>(self) -> Fixture<MC, I>
+    where
+        MC: DbBmc,
+    {
+        Fixture::new(self.rows)
+    }
+}
+
+#[cfg(all(test, feature = "test-fixtures"))]
+mod tests {
+    use sea_query::{enum_def, DynIden, IntoIden, PostgresQueryBuilder, SimpleExpr};
+
+    use super::*;
+    use crate::{base::insert_many_statement, Error};
+
+    #[enum_def]
+    #[allow(unused)]
+    struct Dummy {
+        id: String,
+        name: String,
+    }
+
+    struct DummyBmc;
+
+    impl DbBmc for DummyBmc {
+        const ENTITY: &'static str = "dummy";
+        const TABLE: &'static str = "dummies";
+
+        type Error = Error;
+        type IdType = String;
+
+        fn id_column() -> DynIden {
+            DummyIden::Id.into_iden()
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct DummyCreate {
+        name: String,
+    }
+
+    impl Insert for DummyCreate {
+        fn insert_cols(&self) -> Vec<DynIden> {
+            vec![DummyIden::Name.into_iden()]
+        }
+
+        fn insert_vals(self) -> Vec<SimpleExpr> {
+            vec![self.name.into()]
+        }
+    }
+
+    #[derive(sqlx::FromRow)]
+    #[allow(unused)]
+    struct DummyRow {
+        id: String,
+    }
+
+    impl Select for DummyRow {
+        fn select_cols() -> Vec<DynIden> {
+            vec![DummyIden::Id.into_iden()]
+        }
+    }
+
+    fn create(name: &str) -> DummyCreate {
+        DummyCreate { name: name.into() }
+    }
+
+    #[test]
+    fn building_fixtures() {
+        let fixture = FixtureBuilder::new()
+            .row(create("foo"))
+            .generate(2, || create("gen"))
+            .build::<DummyBmc>();
+
+        let names: Vec<_> = fixture.rows().iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["foo", "gen", "gen"]);
+
+        // applying inserts every row in the one statement
+        let query = insert_many_statement::<DummyBmc, _>(fixture.rows().to_vec()).unwrap();
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"INSERT INTO "dummies" ("name") VALUES ('foo'), ('gen'), ('gen')"#
+        );
+    }
+
+    #[tokio::test]
+    async fn applying_empty_fixtures() {
+        // lazy pools don't connect until used, so no server is needed
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .connect_lazy("postgres://user@localhost/books")
+            .expect("valid url");
+        let mut mm = crate::DbModelManager::from(pool);
+
+        let fixture = FixtureBuilder::<DummyCreate>::new().build::<DummyBmc>();
+        let rows: Vec<DummyRow> = fixture.apply(&mut mm).await.unwrap();
+
+        assert!(rows.is_empty());
+    }
+}
@@ -0,0 +1,80 @@
+//! Helpers for building expressions using Postgres-specific `JSONB`
+//! operators, which `sea_query` has limited support for.
+//!
+//! These are intended for use in [`Filter::filter_query`](crate::Filter)
+//! implementations.
+use sea_query::{Alias, BinOper, DynIden, Expr, Func, SimpleExpr};
+
+/// `col @> value`, where `value` is bound as `JSONB`.
+///
+/// True if the `JSONB` in `col` contains `value`.
+pub fn jsonb_contains(col: DynIden, value: serde_json::Value) -> SimpleExpr {
+    Expr::col(col).binary(BinOper::Custom("@>"), Expr::val(value))
+}
+
+/// `col ? key`.
+///
+/// True if `key` exists as a top-level key (or array element) of the `JSONB`
+/// in `col`.
+pub fn jsonb_key_exists(col: DynIden, key: &str) -> SimpleExpr {
+    Expr::col(col).binary(BinOper::Custom("?"), Expr::val(key))
+}
+
+/// `jsonb_path_exists(col, path)`, where `path` is bound as a `jsonpath`.
+///
+/// True if the `JSONB` in `col` has any item matching `path`.
+pub fn jsonb_path_exists(col: DynIden, path: &str) -> SimpleExpr {
+    Func::cust(Alias::new("jsonb_path_exists"))
+        .arg(Expr::col(col))
+        .arg(Expr::val(path).cast_as(Alias::new("jsonpath")))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_query::{PostgresQueryBuilder, Query};
+
+    use super::*;
+    use crate::iden_from_str;
+
+    fn render(expr: SimpleExpr) -> String {
+        Query::select()
+            .column(iden_from_str("id"))
+            .from(iden_from_str("books"))
+            .and_where(expr)
+            .to_string(PostgresQueryBuilder)
+    }
+
+    #[test]
+    fn contains() {
+        let sql = render(jsonb_contains(
+            iden_from_str("tags"),
+            serde_json::json!(["fantasy", "mystery"]),
+        ));
+
+        assert_eq!(
+            sql,
+            r#"SELECT "id" FROM "books" WHERE "tags" @> E'[\"fantasy\",\"mystery\"]'"#
+        );
+    }
+
+    #[test]
+    fn key_exists() {
+        let sql = render(jsonb_key_exists(iden_from_str("meta"), "spine_size"));
+
+        assert_eq!(
+            sql,
+            r#"SELECT "id" FROM "books" WHERE "meta" ? 'spine_size'"#
+        );
+    }
+
+    #[test]
+    fn path_exists() {
+        let sql = render(jsonb_path_exists(iden_from_str("meta"), "$.spine_size"));
+
+        assert_eq!(
+            sql,
+            r#"SELECT "id" FROM "books" WHERE jsonb_path_exists("meta", CAST('$.spine_size' AS jsonpath))"#
+        );
+    }
+}
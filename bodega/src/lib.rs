@@ -2,17 +2,45 @@
 mod base;
 mod custom_option;
 mod error;
+pub mod expr;
+#[cfg(feature = "test-fixtures")]
+pub mod fixtures;
+#[cfg(feature = "jsonb-helpers")]
+pub mod jsonb;
 mod model_manger;
 mod pagination;
 
 pub use base::{
-    count, create, delete, get, list, list_paginated, update, DbBmcError, DbBmcOp, OpError,
+    count, count_filtered, create, create_many, create_many_chunked, delete, delete_all,
+    delete_where, execute_raw, exists, exists_by_id, exists_where, find, find_optional, first, get,
+    get_many, get_many_by_ids, get_many_optional, get_optional, insert_many_ids, last, list,
+    list_by_cursor, list_deleted, list_filtered, list_in_chunks, list_offset_paginated,
+    list_ordered, list_paginated, list_paginated_after, list_paginated_before,
+    list_paginated_from_cursor, list_paginated_ordered, list_paginated_stable, list_with_options,
+    restore, soft_delete, touch, update, update_all_where, update_conditional,
+    update_returning_old, update_where, upsert, DbBmcError, DbBmcOp, OpError,
 };
-pub use base::{DbBmc, Filter, IdType, Insert, Select, Update};
-pub use custom_option::CustomOption;
+pub use base::{
+    DbBmc, Filter, FilterCondition, IdType, Insert, ListOptions, ListOptionsBuilder, Ordering,
+    Select, Update, UpdateBuilder,
+};
+pub use custom_option::{CustomOption, CustomVecOption};
 pub use error::{Error, Result, SerializationError};
-pub use model_manger::{new_db_pool, AsExecutor, DbModelManager, DbModelManagerError, Transaction};
-pub use pagination::{Cursored, CursoredFilter, Paginated};
+pub use expr::{eq_any, iden_from_str, validate_column_name, SelectStatementExt};
+pub use model_manger::{
+    new_db_pool, new_db_pool_with_options, retry_serializable, run_in_transaction, AsExecutor,
+    CanBeginTransaction, DbModelManager, DbModelManagerError, DbPoolConfig, DbPoolConfigBuilder,
+    IsolationLevel, PoolState, Transaction,
+};
+pub use pagination::{
+    CompoundCursor, CursorError, Cursored, CursoredFilter, OffsetPaginated, Paginated,
+    PaginatedCursor, StablePaginated,
+};
+
+// re-exported so custom queries can be built and bound with the same versions
+// bodega uses, without depending on these crates directly.
+pub use sea_query::PostgresQueryBuilder;
+pub use sea_query_binder::SqlxBinder;
 
 // macros
 
@@ -20,11 +48,32 @@ pub use pagination::{Cursored, CursoredFilter, Paginated};
 /// fields, allowing it to be constructed from a response from a query against
 /// the store.
 ///
+/// Configuration for `#[select(...)]` container attr
+///
+/// * `columns = "..."` *Optional.* A comma-separated list of column names, like
+///   `"id, title"`, to select instead of the variants of the `Iden` enum
+///   generated by `#[sea_query::enum_def]`. Useful when that enum doesn't
+///   exist. The cursor column, if any, is the name of the `cursor` field.
+///
 /// Configuration for `#[select(...)]` field attr
 ///
-/// * `cursor` *Optional - once* Indicate that the annotated field is to be used
-///   for pagination at the store layer. This will cause [`Cursored`] to be
-///   implemented for the struct.
+/// * `cursor` *Optional - at most twice* Indicate that the annotated field is
+///   to be used for pagination at the store layer. This will cause
+///   [`Cursored`] to be implemented for the struct. When two fields are
+///   marked, the `CursorType` is a [`CompoundCursor`] of both, in field order.
+/// * `cursor_type = ...` *Optional.* Only valid with `cursor`. Explicitly
+///   specify the `CursorType` of the generated [`Cursored`] implementation
+///   instead of using the type of the field. The field must implement
+///   `Into<CursorType>`.
+/// * `json` *Optional.* Indicate that the annotated field is stored as JSON(B).
+///   As derives cannot modify the struct, the field must _also_ be marked with
+///   `#[sqlx(json)]`, which will be enforced at compile time.
+/// * `flatten` *Optional.* Inline the columns of a nested struct that also
+///   implements [`Select`]. As with `json`, the field must _also_ be marked with
+///   `#[sqlx(flatten)]`.
+/// * `skip` *Optional.* Exclude the annotated field from the selected columns.
+///   As with `json`, the field must _also_ be marked with `#[sqlx(skip)]`, and
+///   will be populated with its `Default` value.
 ///
 /// # Examples
 /// ```
@@ -49,6 +98,170 @@ pub use pagination::{Cursored, CursoredFilter, Paginated};
 ///     updated_at: DateTime<Utc>,
 /// }
 /// ```
+///
+/// Nested structs can be flattened into the parent.
+/// ```
+/// use bodega::Select;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Address {
+///     street: String,
+///     city: String,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct User {
+///     id: i64,
+///     #[sqlx(flatten)]
+///     #[select(flatten)]
+///     address: Address,
+///     name: String,
+/// }
+///
+/// let cols: Vec<_> = User::select_cols().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(cols, ["id", "street", "city", "name"]);
+/// ```
+///
+/// Expensive columns can be skipped, either on the full model or on a lean
+/// companion model for the same table.
+/// ```
+/// use bodega::Select;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: i64,
+///     title: String,
+///     body: String,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def(table_name = "book")]
+/// pub struct SparseBook {
+///     id: i64,
+///     title: String,
+///     #[sqlx(skip)]
+///     #[select(skip)]
+///     body: String,
+/// }
+///
+/// let cols: Vec<_> = Book::select_cols().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(cols, ["id", "title", "body"]);
+///
+/// let cols: Vec<_> = SparseBook::select_cols().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(cols, ["id", "title"]);
+/// ```
+///
+/// Columns can be listed explicitly when there's no `Iden` enum.
+/// ```
+/// use bodega::{Cursored, Select};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[select(columns = "id, title")]
+/// pub struct Book {
+///     #[select(cursor)]
+///     id: i64,
+///     title: String,
+/// }
+///
+/// let cols: Vec<_> = Book::select_cols().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(cols, ["id", "title"]);
+/// assert_eq!(Book::cursor_column().to_string(), "id");
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[select(columns = "type, name")]
+/// pub struct Tag {
+///     #[select(cursor)]
+///     r#type: String,
+///     name: String,
+/// }
+///
+/// assert_eq!(Tag::cursor_column().to_string(), "type");
+/// ```
+///
+/// The cursor field must be one of the listed columns.
+/// ```compile_fail
+/// use bodega::Select;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[select(columns = "title")]
+/// pub struct Book {
+///     #[select(cursor)]
+///     id: i64,
+///     title: String,
+/// }
+/// ```
+///
+/// The cursor type can differ from the type of the field.
+/// ```
+/// use bodega::{Cursored, Select};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+/// #[sqlx(transparent)]
+/// pub struct Position(i32);
+///
+/// impl From<Position> for i64 {
+///     fn from(value: Position) -> Self {
+///         value.0 as i64
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Chapter {
+///     #[select(cursor, cursor_type = i64)]
+///     position: Position,
+///     title: String,
+/// }
+///
+/// let chapter = Chapter { position: Position(3), title: "three".into() };
+/// let cursor: i64 = chapter.cursor_value();
+/// assert_eq!(cursor, 3);
+/// ```
+///
+/// Paginating by a column that isn't unique needs a compound cursor.
+/// ```
+/// use bodega::{CompoundCursor, Cursored, Select};
+/// use chrono::{DateTime, Utc};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Event {
+///     #[select(cursor)]
+///     created_at: DateTime<Utc>,
+///     #[select(cursor)]
+///     id: i64,
+///     name: String,
+/// }
+///
+/// let created_at = Utc::now();
+/// let event = Event { created_at, id: 7, name: "launch".into() };
+/// assert_eq!(event.cursor_value(), CompoundCursor(created_at, 7));
+///
+/// let cols: Vec<_> = Event::cursor_columns().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(cols, ["created_at", "id"]);
+/// ```
+///
+/// Missing the corresponding `#[sqlx(json)]` is an error.
+/// ```compile_fail
+/// use bodega::Select;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Clone, Serialize, Deserialize)]
+/// pub struct Meta {
+///     spine_size: u32,
+/// }
+///
+/// #[derive(Debug, Clone, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: i64,
+///     #[select(json)]
+///     meta: sqlx::types::Json<Meta>,
+/// }
+/// ```
 pub use bodega_macros::Select;
 
 /// Derives an implementation for [`Insert`] on a struct with named fields,
@@ -62,6 +275,33 @@ pub use bodega_macros::Select;
 /// Configuration for `#[insert(...)]` field attr
 ///
 /// * `iden` *Optional.* Override the computed `Iden` variant for this field.
+/// * `rename = "..."` *Optional.* Use the given column name for this field,
+///   like `"author_name"`, instead of an `Iden` variant. Cannot be combined
+///   with `iden`.
+/// * `cust_opt` *Optional.* Insert `NULL` when this `Option` field is `None`,
+///   via [`CustomOption`]. For `Option<Vec<T>>` fields, [`CustomVecOption`] is
+///   used instead, inserting `Some(vec![])` as an empty array, except for
+///   `Option<Vec<u8>>`, which is inserted as `bytea`.
+/// * `pg_cast = "..."` *Optional.* Explicitly cast the value to the given
+///   postgres type (i.e. `"text"`) when inserting.
+/// * `default_fn = ...` *Optional.* Call the given function for the value when
+///   the field is `None`, like `Utc::now`. As derives cannot remove the field
+///   from the struct, the field must be an `Option` of the function's return
+///   type. Cannot be combined with `cust_opt`.
+/// * `timestamp_fn = "..."` *Optional.* Ignore the value of the field, instead
+///   setting the column to the result of calling the given SQL function, like
+///   `"clock_timestamp"`. Unlike `NOW()`, which is the start of the current
+///   transaction, `clock_timestamp()` is the time the statement executes. As
+///   the value is ignored, the field must be `()` or an `Option`. Cannot be
+///   combined with `cust_opt` or `default_fn`.
+/// * `seq = "..."` *Optional.* Ignore the value of the field, instead setting
+///   the column to the next value of the given postgres sequence, via
+///   `nextval`. As with `timestamp_fn`, the field must be `()` or an `Option`.
+///   Cannot be combined with `cust_opt`, `default_fn`, or `timestamp_fn`.
+/// * `skip` *Optional.* Exclude the field from the insert entirely, leaving
+///   the column to its default, like `DEFAULT NOW()`. As the value is never
+///   used, the field must be an `Option` or `()`. Cannot be combined with any
+///   other field attr.
 ///
 /// # Examples
 /// ```
@@ -92,7 +332,97 @@ pub use bodega_macros::Select;
 ///     // unnecessary override for example
 ///     #[insert(iden = BookIden::Author)]
 ///     author: String,
+///     // unnecessary cast for example
+///     #[insert(pg_cast = "bigint")]
 ///     pages: i64,
+///     #[insert(default_fn = Utc::now)]
+///     created_at: Option<DateTime<Utc>>,
+///     #[insert(timestamp_fn = "clock_timestamp")]
+///     updated_at: (),
+/// }
+/// ```
+///
+/// Fields can be named differently from their columns.
+/// ```
+/// use bodega::{Insert, Select};
+/// use sea_query::{PostgresQueryBuilder, Query};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: i64,
+///     title: String,
+/// }
+///
+/// #[derive(Debug, Clone, Insert)]
+/// #[insert(iden_enum = BookIden)]
+/// pub struct BookCreate {
+///     #[insert(rename = "title")]
+///     book_title: String,
+/// }
+///
+/// let create = BookCreate { book_title: "Dune".into() };
+/// let query = Query::insert()
+///     .into_table(BookIden::Table)
+///     .columns(create.insert_cols())
+///     .values_panic(create.insert_vals())
+///     .to_string(PostgresQueryBuilder);
+/// assert_eq!(query, r#"INSERT INTO "book" ("title") VALUES ('Dune')"#);
+/// ```
+///
+/// Populating a column from a sequence.
+/// ```
+/// use bodega::{Insert, Select};
+/// use sea_query::{PostgresQueryBuilder, Query};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Invoice {
+///     number: i64,
+///     customer: String,
+///     created_at: chrono::DateTime<chrono::Utc>,
+/// }
+///
+/// #[derive(Debug, Clone, Insert)]
+/// #[insert(iden_enum = InvoiceIden)]
+/// pub struct InvoiceCreate {
+///     #[insert(seq = "invoice_number_seq")]
+///     number: (),
+///     customer: String,
+///     // populated by the column default
+///     #[insert(skip)]
+///     created_at: (),
+/// }
+///
+/// let create = InvoiceCreate { number: (), customer: "bob".into(), created_at: () };
+/// let query = Query::insert()
+///     .into_table(InvoiceIden::Table)
+///     .columns(create.insert_cols())
+///     .values_panic(create.insert_vals())
+///     .to_string(PostgresQueryBuilder);
+/// assert_eq!(
+///     query,
+///     r#"INSERT INTO "invoice" ("number", "customer") VALUES (nextval('invoice_number_seq'), 'bob')"#
+/// );
+/// ```
+///
+/// Fields whose value would be silently ignored are an error.
+/// ```compile_fail
+/// use bodega::{Insert, Select};
+/// use chrono::{DateTime, Utc};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: i64,
+///     created_at: DateTime<Utc>,
+/// }
+///
+/// #[derive(Debug, Clone, Insert)]
+/// #[insert(iden_enum = BookIden)]
+/// pub struct BookCreate {
+///     #[insert(timestamp_fn = "clock_timestamp")]
+///     created_at: DateTime<Utc>,
 /// }
 /// ```
 pub use bodega_macros::Insert;
@@ -108,6 +438,16 @@ pub use bodega_macros::Insert;
 /// Configuration for `#[update(...)]` field attr
 ///
 /// * `iden` *Optional.* Override the computed `Iden` variant for this field.
+/// * `rename = "..."` *Optional.* Use the given column name for this field.
+///   See [`Insert`](macro@Insert). Cannot be combined with `iden`.
+/// * `null_if_none` *Optional.* Always update this `Option` field, setting the
+///   column to `NULL` when the value is `None`, instead of skipping it.
+/// * `force` *Optional.* An alias for `null_if_none`.
+/// * `timestamp_fn = "..."` *Optional.* Ignore the value of the field, instead
+///   always setting the column to the result of calling the given SQL
+///   function, like `"clock_timestamp"`. As the value is ignored, the field
+///   must be `()` or an `Option`. See [`Insert`](macro@Insert). Cannot be
+///   combined with `null_if_none`.
 ///
 /// # Examples
 /// ```
@@ -140,6 +480,87 @@ pub use bodega_macros::Insert;
 ///     updated_at: DateTime<Utc>,
 /// }
 /// ```
+///
+/// Fields can be named differently from their columns.
+/// ```
+/// use bodega::{Select, Update};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: i64,
+///     pages: i64,
+/// }
+///
+/// #[derive(Debug, Clone, Update)]
+/// #[update(iden_enum = BookIden)]
+/// pub struct BookUpdate {
+///     #[update(rename = "pages")]
+///     page_count: Option<i64>,
+/// }
+///
+/// let update = BookUpdate { page_count: Some(412) };
+/// let values = update.update_values();
+/// assert_eq!(values.len(), 1);
+/// assert_eq!(values[0].0.to_string(), "pages");
+/// ```
+///
+/// Optional columns can be explicitly set to `NULL`.
+/// ```
+/// use bodega::{Select, Update};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: i64,
+///     title: String,
+///     subtitle: Option<String>,
+///     series_id: Option<i64>,
+/// }
+///
+/// #[derive(Debug, Clone, Update)]
+/// #[update(iden_enum = BookIden)]
+/// pub struct BookUpdate {
+///     title: Option<String>,
+///     #[update(null_if_none)]
+///     subtitle: Option<String>,
+///     #[update(force)]
+///     series_id: Option<i64>,
+/// }
+///
+/// let update = BookUpdate { title: None, subtitle: None, series_id: None };
+/// let values = update.update_values();
+/// assert_eq!(values.len(), 2);
+/// assert_eq!(values[0].0.to_string(), "subtitle");
+/// assert_eq!(values[1].0.to_string(), "series_id");
+/// ```
+///
+/// Timestamps can be set by the database.
+/// ```
+/// use bodega::{Select, Update};
+/// use chrono::{DateTime, Utc};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: i64,
+///     title: String,
+///     updated_at: DateTime<Utc>,
+/// }
+///
+/// #[derive(Debug, Clone, Update)]
+/// #[update(iden_enum = BookIden)]
+/// pub struct BookUpdate {
+///     title: Option<String>,
+///     #[update(timestamp_fn = "clock_timestamp")]
+///     updated_at: (),
+/// }
+///
+/// let update = BookUpdate { title: None, updated_at: () };
+/// let values = update.update_values();
+/// assert_eq!(values.len(), 1);
+/// assert_eq!(values[0].0.to_string(), "updated_at");
+/// ```
 pub use bodega_macros::Update;
 
 /// Implement [`DbBmc`] on a type, and optionally add basic CRUD implementations.
@@ -155,37 +576,167 @@ pub use bodega_macros::Update;
 /// * `model_name = "..."` *Optional.* A specific model name like `"foo"`, for use when
 ///   deriving the model name from the `model` is not sufficient.
 /// * `table_name = "..."` *Optional.* A specific table name like `"foos"`, for use when
-///   deriving the table name from the model name is not sufficient.
+///   deriving the table name from the model name is not sufficient. By
+///   default, the table name is the model name pluralized, like `"boxes"` for
+///   `"box"` or `"categories"` for `"category"`. Model names ending in `s`,
+///   like `"settings"` or `"address"`, are used as-is.
+/// * `schema = "..."` *Optional.* The schema the table resides in, like
+///   `"audit"`, qualifying the table in queries via [`DbBmc::TABLE_SCHEMA`].
+/// * `table_alias = "..."` *Optional.* An alias for the table in queries, like
+///   `"b"`, for use alongside raw queries referring to the table by it. See
+///   [`DbBmc::TABLE_ALIAS`].
 /// * `id_iden = ...` *Optional.* Override the derived `Iden` enum for this `model`.
+///   Note that [`DbBmc::id_column_name`] will still be `"id"` in this case.
+/// * `model_id_field = "..."` *Optional.* The name of the id field on the
+///   `model`, like `"book_id"`, for use when it isn't `id`. The `Iden` variant
+///   is derived from this (`BookIden::BookId`). Cannot be combined with
+///   `id_iden` or `id_column`.
+/// * `id_column = "..."` *Optional.* The name of the id column, like
+///   `"external_id"`, for use when there's no corresponding `Iden` variant.
+///   Cannot be combined with `id_iden` or `model_id_field`.
 /// * `error = ...` *Optional.* Return this error type instead of [`crate::Error`].
 ///   The type passed must implement `From<bodega::Error>`.
 /// * `private_methods` *Optional.* Generate methods as private methods, prefixed
 ///   with an underscore `_`. Useful if you still need to wrap the generated
 ///   method in something like an internal transaction or some sort of authz.
 ///   The type passed must implement `From<bodega::Error>`.
+/// * `auto_filter = ...` *Optional.* Generate an empty [`Filter`] impl for the
+///   specified type, marked as a no-op with [`Filter::is_noop`]. Useful for
+///   filters that only paginate and don't add any conditions. Providing your
+///   own `impl Filter` for the same type will result in a conflicting
+///   implementation error.
+/// * `soft_delete` or `soft_delete = "..."` *Optional.* Soft-delete rows by
+///   setting the given column (`deleted_at` by default) to `NOW()`, via
+///   [`DbBmc::SOFT_DELETE_COLUMN`]. Generated methods then exclude
+///   soft-deleted rows when fetching, counting, or updating, and
+///   `soft_delete`, `restore`, and `list_deleted` methods are generated in
+///   addition to any in `methods`.
+/// * `timestamps` or `timestamps(created_at_col = "...", updated_at_col = "...")`
+///   *Optional.* Set the given columns (`created_at` and `updated_at` by
+///   default) to `NOW()` when creating rows, and the updated at column when
+///   updating them, via [`DbBmc::CREATED_AT_COLUMN`] and
+///   [`DbBmc::UPDATED_AT_COLUMN`]. Values provided by the [`Insert`] or
+///   [`Update`] take precedence.
 /// * `methods(...)` *Optional.* A comma-separated list of methods to implement
 ///   from the following:
-///   * `create = ...`, `get`, `list`, `list_paginated = ...`, `update = ...`, `delete`, `count`.
+///   * `create = ...`, `create_many = ...`, `get`, `get_arc`, `get_optional`,
+///     `get_many`, `get_many_optional`, `get_many_by_ids`, `find = ...`,
+///     `find_optional = ...`, `first`, `last`, `list`, `list_ordered`,
+///     `list_filtered = ...`,
+///     `list_paginated = ...`, `list_by_cursor`, `list_offset_paginated = ...`,
+///     `update = ...`, `update_conditional`, `update_all_where = (...)`,
+///     `update_where = (..., ...)`, `upsert_by = (...)`,
+///     `upsert = (..., ...)`, `delete`, `delete_where = ...`,
+///     `batch_delete_where = ...`, `count`, `count_filtered = ...`,
+///     `exists`, `exists_where = ...`.
+///
+///   Any of these can instead be given as a list with a `vis`, like
+///   `get(vis = "pub(crate)")` or `create(BookCreate, vis = "")`, to generate
+///   that method with the given visibility. This takes precedence over
+///   `private_methods`, so the method is not prefixed with an underscore.
 ///
 /// Specific configuration for `#[db_bmc(methods(...))]`
 ///
 /// * `create = ...` Generate a `create` method on the controller accepting an
 ///   instance of the specified type that implements [`Insert`]. Returns the
 ///   created instance as an instance of `model`.
+/// * `create_many = ...` Generate a `create_many` method on the controller
+///   accepting a `Vec` of the specified type that implements [`Insert`],
+///   inserting them in a single statement. Returns the created instances as
+///   instances of `model`, or [`Error::EmptyInsert`] if there were none.
 /// * `get` Generate a `get` method on the controller accepting an id. Returns
 ///   the corresponding instance of the `model` on success.
+/// * `get_arc` Generate a `get_arc` method on the controller accepting an id.
+///   Like `get`, but returns the instance wrapped in an [`Arc`](std::sync::Arc)
+///   for sharing.
+/// * `get_optional` Generate a `get_optional` method on the controller
+///   accepting an id. Like `get`, but returns `None` instead of
+///   [`Error::EntityNotFound`] if there is no such instance.
+/// * `get_many` Generate a `get_many` method on the controller accepting a
+///   slice of ids. Returns a [`Vec<T>`] of the `model` in the order of the ids,
+///   or [`Error::EntityNotFound`] for the first id that doesn't exist.
+/// * `get_many_optional` Like `get_many`, but generating a `get_many_optional`
+///   method returning a [`Vec<Option<T>>`] with `None` for the ids that don't
+///   exist.
+/// * `get_many_by_ids` Generate a `get_many_by_ids` method on the controller
+///   accepting a slice of ids. Returns a [`Vec<T>`] of the `model` for each id
+///   that exists, ignoring any that don't.
+/// * `find = ...` Generate a `find` method on the controller using the
+///   specified type as the [`Filter`]. Returns the single instance of the
+///   `model` satisfying the filters, or [`Error::EntityNotFoundWhere`] if there
+///   is none. Intended for lookups by unique columns other than the id.
+/// * `find_optional = ...` Like `find`, but generating a `find_optional`
+///   method returning `None` instead of an error if there is no such instance.
+/// * `first` Generate a `first` method on the controller. Returns the instance
+///   of the `model` with the lowest id, or `None` if the table is empty.
+/// * `last` Generate a `last` method on the controller. Returns the instance
+///   of the `model` with the highest id, or `None` if the table is empty.
 /// * `list` Generate a `list` method on the controller. Returns a [`Vec<T>`]
 ///   of the specified `model` containing every row from the store.
+/// * `list_ordered` Generate a `list_ordered` method on the controller
+///   accepting a slice of [`Ordering`]. Like `list`, but ordered by each of the
+///   orderings in turn.
+/// * `list_filtered = ...` Generate a `list_filtered` method on the controller
+///   using the specified type as the [`Filter`]. Returns a [`Vec<T>`] of the
+///   specified `model` containing every row that satisfies the filters,
+///   without pagination.
 /// * `list_paginated = ...` Generate a `list_paginated` method on the controller
 ///   using the specified type as the [`Filter`]/[`CursoredFilter`]. Returns
 ///   a single page [`Paginated<T>`] of the given `model` that satisfies the
 ///   filters.
+///   The order can be specified with
+///   `list_paginated = (filter = ..., order_by = ..., order = asc|desc)`,
+///   where `order_by` and `order` are optional, overriding
+///   [`CursoredFilter::cursor_column_order`]. See [`list_paginated_ordered`].
+/// * `list_by_cursor` Generate a `list_by_cursor` method on the controller
+///   accepting an optional cursor and a limit, for when the cursor is the only
+///   filter. Returns a single page [`Paginated<T>`] of the given `model`.
+/// * `list_offset_paginated = ...` Generate a `list_offset_paginated` method
+///   on the controller using the specified type as the [`Filter`], accepting a
+///   zero-based page and page size. Returns an [`OffsetPaginated<T>`] of the
+///   given `model` that satisfies the filters.
 /// * `update = ...` Generate an `update` method on the controller accepting an
 ///   id and the specified type. Returns the updated instance as an instance of
 ///   `model`.
+/// * `update_conditional` Generate an `update_conditional` method on the
+///   controller accepting an id and a function that sets values on an
+///   [`UpdateBuilder`]. Returns the updated instance as an instance of `model`.
+/// * `update_all_where = (data = ..., filter = ...)` Generate an
+///   `update_all_where` method on the controller accepting an instance of the
+///   `data` type and a reference to the `filter` type, updating every row that
+///   satisfies the filters. Returns the number of rows updated.
+/// * `update_where = (..., ...)` Generate an `update_where` method on the
+///   controller accepting a reference to the second type, the [`Filter`], and
+///   an instance of the first type, which implements [`Update`], updating
+///   every row that satisfies the filters. Returns the updated instances as
+///   instances of `model`.
+/// * `upsert = (..., ...)` Generate an `upsert` method on the controller
+///   accepting an instance of the first type, which implements [`Insert`], the
+///   columns to detect conflicts on, and an instance of the second type, which
+///   implements [`Update`]. Returns the created or updated instance as an
+///   instance of `model`.
+/// * `upsert_by = (conflict_cols = [...], update_type = ...)` Like `upsert`,
+///   but generating an `upsert_by` method with the conflict columns fixed at
+///   compile time, accepting an instance of the `create` type and an instance
+///   of `update_type`. A different [`Insert`] type can be specified with
+///   `create_type = ...`, which is required if `create` is not specified.
 /// * `delete` Generate a `delete` method on the controller accepting an id.
+/// * `delete_where = ...` Generate a `delete_where` method on the controller
+///   using the specified type as the [`Filter`]. Returns the number of rows
+///   deleted. A filter that adds no conditions results in
+///   [`Error::EmptyFilter`], rather than deleting every row.
+/// * `batch_delete_where = ...` The same as `delete_where`, but generating a
+///   method named `batch_delete_where`, to better distinguish it from `delete`.
 /// * `count` Generate a `count` method on the controller returning the count of
 ///   all rows of this controller's model in the store.
+/// * `count_filtered = ...` Generate a `count_filtered` method on the
+///   controller using the specified type as the [`Filter`]. Returns the count
+///   of rows that satisfy the filters.
+/// * `exists` Generate an `exists` method on the controller accepting an id.
+///   Returns whether a row with that id exists, without fetching it.
+/// * `exists_where = ...` Generate an `exists_where` method on the controller
+///   using the specified type as the [`Filter`]. Returns whether any rows
+///   satisfy the filters.
 ///
 /// # Examples
 /// ```
@@ -282,6 +833,202 @@ pub use bodega_macros::Update;
 ///
 /// assert_eq!(BookBmc::ENTITY, "book");
 /// assert_eq!(BookBmc::TABLE, "books");
+///
+/// let entity_name: fn() -> &'static str = BookBmc::entity_name;
+/// assert_eq!(entity_name(), "book");
+/// ```
+///
+/// Filters that only paginate can have their [`Filter`] impl generated.
+/// ```
+/// # use bodega::{Select, CursoredFilter, DbBmc, uuid_id};
+/// # use serde::{Deserialize, Serialize};
+/// # use uuid::Uuid;
+/// #
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// # #[uuid_id]
+/// # pub struct BookId(Uuid);
+/// #
+/// # #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// # #[sea_query::enum_def]
+/// # pub struct Book {
+/// #     #[select(cursor)]
+/// #     id: BookId,
+/// #     title: String,
+/// # }
+/// #
+/// #[derive(Debug, Clone, Default)]
+/// pub struct BookPages {
+///     limit: usize,
+///     cursor: Option<BookId>,
+/// }
+///
+/// impl CursoredFilter for BookPages {
+///     type Entity = Book;
+///
+///     fn cursor(&self) -> Option<<Self::Entity as bodega::Cursored>::CursorType> {
+///         self.cursor
+///     }
+///
+///     fn set_cursor(&mut self, cursor: <Self::Entity as bodega::Cursored>::CursorType) {
+///         self.cursor = Some(cursor);
+///     }
+///
+///     fn page_limit(&self) -> usize {
+///         self.limit
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, DbBmc)]
+/// #[db_bmc(
+///     model = Book,
+///     id_type = BookId,
+///     auto_filter = BookPages,
+///     methods(list_paginated = BookPages)
+/// )]
+/// pub struct BookBmc;
+/// ```
+///
+/// Methods can be given their own visibility, e.g. to keep `delete` internal
+/// while the rest are private for wrapping.
+/// ```
+/// # use bodega::{Select, DbBmc, uuid_id};
+/// # use serde::{Deserialize, Serialize};
+/// # use uuid::Uuid;
+/// #
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// # #[uuid_id]
+/// # pub struct BookId(Uuid);
+/// #
+/// # #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// # #[sea_query::enum_def]
+/// # pub struct Book {
+/// #     id: BookId,
+/// #     title: String,
+/// # }
+/// #
+/// #[derive(Debug, Clone, DbBmc)]
+/// #[db_bmc(
+///     model = Book,
+///     id_type = BookId,
+///     private_methods,
+///     methods(get(vis = "pub"), list, delete(vis = "pub(crate)"))
+/// )]
+/// pub struct BookBmc;
+///
+/// # async fn wrap(mut mm: bodega::DbModelManager, id: BookId) -> bodega::Result<()> {
+/// let book = BookBmc::get(&mut mm, &id).await?;
+/// let books = BookBmc::_list(&mut mm).await?;
+/// BookBmc::delete(&mut mm, &book.id).await?;
+/// # let _ = books;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Models whose id field isn't named `id` can specify the field name.
+/// ```
+/// # use bodega::{Select, DbBmc, uuid_id};
+/// # use serde::{Deserialize, Serialize};
+/// # use uuid::Uuid;
+/// #
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// # #[uuid_id]
+/// # pub struct BookId(Uuid);
+/// #
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     book_id: BookId,
+///     title: String,
+/// }
+///
+/// #[derive(Debug, Clone, DbBmc)]
+/// #[db_bmc(model = Book, id_type = BookId, model_id_field = "book_id")]
+/// pub struct BookBmc;
+///
+/// assert_eq!(BookBmc::id_column().to_string(), "book_id");
+/// assert_eq!(BookBmc::id_column_name(), "book_id");
+/// ```
+///
+/// Rows can be soft-deleted instead, which `get` and `list` then exclude.
+/// ```
+/// use bodega::{DbBmc, DbModelManager, Select, uuid_id};
+/// use chrono::{DateTime, Utc};
+/// use serde::{Deserialize, Serialize};
+/// use uuid::Uuid;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// #[uuid_id]
+/// pub struct BookId(Uuid);
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: BookId,
+///     title: String,
+///     removed_at: Option<DateTime<Utc>>,
+/// }
+///
+/// #[derive(Debug, Clone, DbBmc)]
+/// #[db_bmc(
+///     model = Book,
+///     id_type = BookId,
+///     soft_delete = "removed_at",
+///     methods(get, list),
+/// )]
+/// pub struct BookBmc;
+///
+/// assert_eq!(BookBmc::SOFT_DELETE_COLUMN, Some("removed_at"));
+///
+/// // generated alongside get and list
+/// let _ = BookBmc::soft_delete::<DbModelManager>;
+/// let _ = BookBmc::restore::<DbModelManager>;
+/// let _ = BookBmc::list_deleted::<DbModelManager>;
+/// ```
+///
+/// Timestamp columns can be maintained automatically.
+/// ```
+/// # use bodega::{DbBmc, Select, uuid_id};
+/// # use chrono::{DateTime, Utc};
+/// # use serde::{Deserialize, Serialize};
+/// # use uuid::Uuid;
+/// #
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// # #[uuid_id]
+/// # pub struct BookId(Uuid);
+/// #
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: BookId,
+///     title: String,
+///     added_at: DateTime<Utc>,
+///     updated_at: DateTime<Utc>,
+/// }
+///
+/// #[derive(Debug, Clone, DbBmc)]
+/// #[db_bmc(model = Book, id_type = BookId, timestamps(created_at_col = "added_at"))]
+/// pub struct BookBmc;
+///
+/// assert_eq!(BookBmc::CREATED_AT_COLUMN, Some("added_at"));
+/// assert_eq!(BookBmc::UPDATED_AT_COLUMN, Some("updated_at"));
+/// ```
+///
+/// The `id_type` must implement [`IdType`], which is checked at compile time.
+/// ```compile_fail
+/// use bodega::{DbBmc, Select};
+///
+/// pub struct NotAnId;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Select)]
+/// #[sea_query::enum_def]
+/// pub struct Book {
+///     id: i64,
+///     title: String,
+/// }
+///
+/// #[derive(Debug, Clone, DbBmc)]
+/// #[db_bmc(model = Book, id_type = NotAnId)]
+/// pub struct BookBmc;
 /// ```
 pub use bodega_macros::DbBmc;
 
@@ -303,6 +1050,18 @@ pub use bodega_macros::JsonValue;
 /// Modifies a newtype in the form of `Foo(Uuid)` to have functionality that
 /// makes it compatible with a store layer.
 ///
+/// With `#[uuid_id(iden)]`, `sea_query::Iden` is also implemented for the
+/// newtype using the snake_case name of the type (i.e. `book_id`), allowing it
+/// to be used as a column reference. This is opt-in because both
+/// `sea_query::Iden` and [`ToString`] provide a `to_string` method, making
+/// calls to it ambiguous wherever `Iden` is in scope.
+///
+/// With `#[uuid_id(ord_by_time)]`, `PartialOrd` and `Ord` are implemented by
+/// comparing the timestamps of the uuids, falling back to the bytes. For the
+/// v7 uuids generated by default, this is equivalent to deriving them, but
+/// makes the time ordering explicit. Do not also derive `PartialOrd` or `Ord`
+/// in this case.
+///
 /// # Examples
 /// ```
 /// use bodega::uuid_id;
@@ -313,8 +1072,78 @@ pub use bodega_macros::JsonValue;
 /// #[uuid_id]
 /// pub struct BookId(Uuid);
 /// ```
+///
+/// As a column reference.
+/// ```
+/// use bodega::uuid_id;
+/// use uuid::Uuid;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// #[uuid_id(iden)]
+/// pub struct BookId(Uuid);
+///
+/// assert_eq!(sea_query::Iden::to_string(&BookId::default()), "book_id");
+///
+/// // and, as with any `Iden`, used wherever an `IntoIden` is expected
+/// let query = sea_query::Query::select()
+///     .column(BookId::default())
+///     .from(sea_query::Alias::new("review"))
+///     .to_string(sea_query::PostgresQueryBuilder);
+/// assert_eq!(query, r#"SELECT "book_id" FROM "review""#);
+/// ```
+///
+/// Ordering by time.
+/// ```
+/// use bodega::uuid_id;
+/// use uuid::Uuid;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// #[uuid_id(ord_by_time)]
+/// pub struct BookId(Uuid);
+///
+/// let first = BookId::default();
+/// std::thread::sleep(std::time::Duration::from_millis(2));
+/// let second = BookId::default();
+///
+/// assert!(first < second);
+/// ```
 pub use bodega_macros::uuid_id;
 
+/// Modifies a newtype in the form of `Foo(i64)` to have functionality that
+/// makes it compatible with a store layer, for ids assigned by the database,
+/// like `BIGSERIAL` columns.
+///
+/// This is the equivalent of [`uuid_id`](macro@uuid_id), accepting the same
+/// `skip_*` options, except that `Default` is _not_ implemented unless
+/// `#[i64_id(skip_default = false)]` is specified, as the database assigns
+/// the value. Neither `iden` nor `ord_by_time` are supported.
+///
+/// # Examples
+/// ```
+/// use bodega::i64_id;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// #[i64_id]
+/// pub struct AuthorId(i64);
+///
+/// let id = AuthorId::from(5);
+/// assert_eq!(*id, 5);
+/// assert_eq!(id.to_string(), "5");
+/// ```
+///
+/// With an explicit `Default`.
+/// ```
+/// use bodega::i64_id;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// #[i64_id(skip_default = false)]
+/// pub struct AuthorId(i64);
+///
+/// assert_eq!(*AuthorId::default(), 0);
+/// ```
+pub use bodega_macros::i64_id;
+
 /// Modifies an enum corresponding to a postgres enum to support various
 /// `sea_query` operations.
 ///
@@ -341,3 +1170,42 @@ pub use bodega_macros::uuid_id;
 /// }
 /// ```
 pub use bodega_macros::store_enum;
+
+// compile-time checks of the thread-safety guarantees of the public types
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    struct Dummy;
+
+    impl Cursored for Dummy {
+        type CursorType = i64;
+
+        fn cursor_value(&self) -> Self::CursorType {
+            0
+        }
+
+        fn cursor_column() -> sea_query::DynIden {
+            iden_from_str("id")
+        }
+    }
+
+    #[test]
+    fn public_types_are_send_sync() {
+        assert_send_sync::<DbModelManager>();
+        assert_send_sync::<Error>();
+        assert_send_sync::<DbBmcError>();
+        assert_send_sync::<DbModelManagerError>();
+        assert_send_sync::<CursorError>();
+        assert_send_sync::<UpdateBuilder>();
+        assert_send_sync::<ListOptions>();
+        assert_send_sync::<Ordering>();
+        assert_send_sync::<Paginated<Dummy>>();
+        assert_send_sync::<OffsetPaginated<Dummy>>();
+        assert_send_sync::<StablePaginated<Dummy, i64>>();
+        assert_send_sync::<PaginatedCursor<i64>>();
+        assert_send_sync::<Transaction<'static>>();
+    }
+}
@@ -1,4 +1,11 @@
-use std::fmt::Debug;
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use snafu::{ResultExt, Snafu};
 
 /// Indicated that the given type can produce a cursor for use in pagination.
 pub trait Cursored {
@@ -10,6 +17,33 @@ pub trait Cursored {
 
     /// Get a reference to the column corresponding to the cursor (i.e. `id`).
     fn cursor_column() -> sea_query::DynIden;
+
+    /// Get all of the columns corresponding to the cursor, in order.
+    ///
+    /// This is only more than [`Cursored::cursor_column`] for a
+    /// [`CompoundCursor`], where the columns are compared together as a row
+    /// value, like `(created_at, id) > ($1, $2)`.
+    fn cursor_columns() -> Vec<sea_query::DynIden> {
+        vec![Self::cursor_column()]
+    }
+}
+
+/// A cursor made up of the values of two columns, for stable pagination by a
+/// column that isn't unique, like `(created_at, id)`.
+///
+/// As postgres compares row values with `NULL`s as unknown, neither column
+/// should be nullable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CompoundCursor<A, B>(pub A, pub B);
+
+impl<A, B> From<CompoundCursor<A, B>> for sea_query::SimpleExpr
+where
+    A: Into<sea_query::SimpleExpr>,
+    B: Into<sea_query::SimpleExpr>,
+{
+    fn from(value: CompoundCursor<A, B>) -> Self {
+        sea_query::SimpleExpr::Tuple(vec![value.0.into(), value.1.into()])
+    }
 }
 
 /// Indicates the given type can be used for filtering paginated entries for a
@@ -29,6 +63,23 @@ pub trait CursoredFilter {
     /// `None`).
     fn set_cursor(&mut self, cursor: <Self::Entity as Cursored>::CursorType);
 
+    /// Get the cursor for requesting the page _before_ it, if present.
+    ///
+    /// When this is set, it takes precedence over [`CursoredFilter::cursor`],
+    /// and [`list_paginated`](crate::list_paginated) returns the page of
+    /// entries preceding it. Defaults to `None`, for filters that only
+    /// support walking forwards.
+    fn prev_cursor(&self) -> Option<<Self::Entity as Cursored>::CursorType> {
+        None
+    }
+
+    /// Sets the previous page cursor to the specified value.
+    ///
+    /// Like [`CursoredFilter::set_cursor`], this does not support clearing
+    /// the cursor. The default implementation ignores the value, so filters
+    /// supporting [`CursoredFilter::prev_cursor`] should override both.
+    fn set_prev_cursor(&mut self, _cursor: <Self::Entity as Cursored>::CursorType) {}
+
     /// Get the page limit.
     fn page_limit(&self) -> usize;
 
@@ -39,6 +90,20 @@ pub trait CursoredFilter {
     fn cursor_column_order() -> sea_query::Order {
         sea_query::Order::Asc
     }
+
+    /// Whether `NULL`s in the cursor column are ordered last (`NULLS LAST`)
+    /// or first (`NULLS FIRST`), regardless of the order.
+    ///
+    /// Defaults to `None`, ordering them as postgres does by default, which is
+    /// last when ascending but first when descending.
+    ///
+    /// Note that the condition restricting a page to the rows after the
+    /// cursor (i.e. `col > $1`) never matches `NULL`, so rows with a `NULL`
+    /// cursor column are only ever returned on a page requested without a
+    /// cursor. Cursor columns should generally be `NOT NULL`.
+    fn cursor_nulls_last() -> Option<bool> {
+        None
+    }
 }
 
 /// A wrapper around the entities returned from the database that also includes
@@ -47,6 +112,7 @@ pub trait CursoredFilter {
 pub struct Paginated<T: Cursored> {
     pub entries: Vec<T>,
     pub next_cursor: Option<<T as Cursored>::CursorType>,
+    pub prev_cursor: Option<<T as Cursored>::CursorType>,
     pub limit: usize,
 }
 
@@ -55,6 +121,7 @@ impl<T: Cursored> Default for Paginated<T> {
         Self {
             entries: Vec::default(),
             next_cursor: None,
+            prev_cursor: None,
             limit: 10,
         }
     }
@@ -77,6 +144,7 @@ where
         Self {
             entries,
             next_cursor,
+            prev_cursor: None,
             limit,
         }
     }
@@ -84,6 +152,281 @@ where
     pub fn has_next(&self) -> bool {
         self.next_cursor.is_some()
     }
+
+    /// Whether there may be a page of entries before this one.
+    ///
+    /// Pages built with [`Paginated::new`] do not set the previous cursor, but
+    /// [`list_paginated`](crate::list_paginated) sets it to the cursor of the
+    /// first entry whenever the page is not empty and was not the first page.
+    pub fn has_previous(&self) -> bool {
+        self.prev_cursor.is_some()
+    }
+
+    /// The entries of this page.
+    pub fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// Consume the page, returning its entries.
+    pub fn take_entries(self) -> Vec<T> {
+        self.entries
+    }
+}
+
+impl<T> From<Paginated<T>> for (Vec<T>, Option<<T as Cursored>::CursorType>)
+where
+    T: Cursored,
+{
+    fn from(value: Paginated<T>) -> Self {
+        (value.entries, value.next_cursor)
+    }
+}
+
+/// Discards everything but the entries, so the cursors are lost.
+impl<T> From<Paginated<T>> for Vec<T>
+where
+    T: Cursored,
+{
+    fn from(value: Paginated<T>) -> Self {
+        value.entries
+    }
+}
+
+/// A single page containing all of the entries, with a limit of the number of
+/// entries and no cursors, as there is nothing before or after it.
+impl<T> From<Vec<T>> for Paginated<T>
+where
+    T: Cursored,
+{
+    fn from(entries: Vec<T>) -> Self {
+        Self {
+            limit: entries.len(),
+            entries,
+            next_cursor: None,
+            prev_cursor: None,
+        }
+    }
+}
+
+/// Equivalent to calling [`Paginated::new`] with `(entries, limit)`.
+impl<T> From<(Vec<T>, usize)> for Paginated<T>
+where
+    T: Cursored,
+{
+    fn from((entries, limit): (Vec<T>, usize)) -> Self {
+        Self::new(entries, limit)
+    }
+}
+
+// the serialized form of a page, with the cursors as opaque
+// `PaginatedCursor` strings, so they can be passed back in query parameters.
+#[derive(Serialize)]
+struct PaginatedRef<'a, T, C> {
+    entries: &'a [T],
+    next_cursor: Option<PaginatedCursor<&'a C>>,
+    prev_cursor: Option<PaginatedCursor<&'a C>>,
+    limit: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>, C: DeserializeOwned"))]
+struct PaginatedRepr<T, C> {
+    entries: Vec<T>,
+    next_cursor: Option<PaginatedCursor<C>>,
+    prev_cursor: Option<PaginatedCursor<C>>,
+    limit: usize,
+}
+
+/// The cursors are serialized as opaque strings, as with [`PaginatedCursor`].
+impl<T> Serialize for Paginated<T>
+where
+    T: Cursored + Serialize,
+    <T as Cursored>::CursorType: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PaginatedRef {
+            entries: &self.entries,
+            next_cursor: self.next_cursor.as_ref().map(PaginatedCursor),
+            prev_cursor: self.prev_cursor.as_ref().map(PaginatedCursor),
+            limit: self.limit,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Paginated<T>
+where
+    T: Cursored + Deserialize<'de>,
+    <T as Cursored>::CursorType: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = PaginatedRepr::<T, <T as Cursored>::CursorType>::deserialize(deserializer)?;
+
+        Ok(Self {
+            entries: repr.entries,
+            next_cursor: repr.next_cursor.map(PaginatedCursor::into_inner),
+            prev_cursor: repr.prev_cursor.map(PaginatedCursor::into_inner),
+            limit: repr.limit,
+        })
+    }
+}
+
+/// A page of entries from [`list_paginated_stable`](crate::list_paginated_stable),
+/// where the cursor for the next page includes the id of the last entry as a
+/// tiebreaker.
+#[derive(Debug, Clone)]
+pub struct StablePaginated<T: Cursored, I> {
+    pub entries: Vec<T>,
+    /// The cursor and id of the last entry, if there may be more entries.
+    pub next_cursor: Option<(<T as Cursored>::CursorType, I)>,
+    pub limit: usize,
+}
+
+impl<T, I> StablePaginated<T, I>
+where
+    T: Cursored,
+{
+    pub fn has_next(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    /// The entries of this page.
+    pub fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// Consume the page, returning its entries.
+    pub fn take_entries(self) -> Vec<T> {
+        self.entries
+    }
+}
+
+/// A page of entries selected by offset, along with the information needed to
+/// display, e.g., "page 3 of 47".
+///
+/// Unlike [`Paginated`], this does not require the entries be [`Cursored`].
+#[derive(Debug, Clone)]
+pub struct OffsetPaginated<T> {
+    pub entries: Vec<T>,
+    /// The total number of entries across all pages.
+    pub total_count: usize,
+    /// The zero-based index of this page.
+    pub page: usize,
+    pub page_size: usize,
+}
+
+impl<T> OffsetPaginated<T> {
+    /// The total number of pages, or `0` if the page size is `0`.
+    pub fn total_pages(&self) -> usize {
+        if self.page_size == 0 {
+            return 0;
+        }
+
+        self.total_count.div_ceil(self.page_size)
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.page + 1 < self.total_pages()
+    }
+
+    pub fn has_previous(&self) -> bool {
+        self.page > 0
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum CursorError {
+    #[snafu(display("Invalid cursor encoding: "))]
+    Encoding { source: base64::DecodeError },
+
+    #[snafu(display("Invalid cursor value: "))]
+    Value { source: serde_json::Error },
+}
+
+/// A wrapper around a cursor value that (de)serializes as an opaque string.
+///
+/// The inner value is serialized as JSON and then base64 (url-safe) encoded,
+/// making it safe to pass around in query parameters without exposing the
+/// underlying value. This is not encryption, so a determined client _can_
+/// still decode and tamper with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PaginatedCursor<T>(T);
+
+impl<T> PaginatedCursor<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Get a reference to the cursor value.
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Consume this, returning the cursor value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for PaginatedCursor<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Display for PaginatedCursor<T>
+where
+    T: Serialize,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let raw = serde_json::to_vec(&self.0).map_err(|_| std::fmt::Error)?;
+        f.write_str(&URL_SAFE_NO_PAD.encode(raw))
+    }
+}
+
+impl<T> FromStr for PaginatedCursor<T>
+where
+    T: DeserializeOwned,
+{
+    type Err = CursorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw = URL_SAFE_NO_PAD.decode(s).context(EncodingSnafu)?;
+        let value = serde_json::from_slice(&raw).context(ValueSnafu)?;
+
+        Ok(Self(value))
+    }
+}
+
+impl<T> Serialize for PaginatedCursor<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for PaginatedCursor<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
 }
 
 #[cfg(test)]
@@ -93,6 +436,7 @@ mod tests {
     use super::*;
 
     #[enum_def]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
     struct Dummy {
         id: i64,
     }
@@ -140,4 +484,110 @@ mod tests {
         let p = Paginated::new(entries, num);
         assert_eq!(p.next_cursor, None);
     }
+
+    #[test]
+    fn prev_cursor_none_by_default() {
+        let p = Paginated::new(entries(), 10);
+        assert_eq!(p.prev_cursor, None);
+        assert!(!p.has_previous());
+    }
+
+    #[test]
+    fn offset_pages() {
+        let p = OffsetPaginated {
+            entries: entries(),
+            total_count: 21,
+            page: 0,
+            page_size: 10,
+        };
+        assert_eq!(p.total_pages(), 3);
+        assert!(p.has_next());
+        assert!(!p.has_previous());
+
+        let p = OffsetPaginated { page: 2, ..p };
+        assert!(!p.has_next());
+        assert!(p.has_previous());
+
+        let p = OffsetPaginated {
+            entries: Vec::<Dummy>::new(),
+            total_count: 0,
+            page: 0,
+            page_size: 0,
+        };
+        assert_eq!(p.total_pages(), 0);
+        assert!(!p.has_next());
+    }
+
+    #[test]
+    fn tuple_conversions() {
+        let (entries, cursor): (Vec<Dummy>, Option<i64>) = Paginated::new(entries(), 10).into();
+        assert_eq!(entries.len(), 10);
+        assert_eq!(cursor, Some(10));
+
+        let p: Paginated<Dummy> = (entries, 11).into();
+        assert_eq!(p.next_cursor, None);
+        assert_eq!(p.limit, 11);
+    }
+
+    #[test]
+    fn vec_conversions() {
+        let entries: Vec<Dummy> = Paginated::new(entries(), 10).into();
+        assert_eq!(entries.len(), 10);
+
+        let p: Paginated<Dummy> = entries.into();
+        assert_eq!(p.limit, 10);
+        assert_eq!(p.next_cursor, None);
+        assert_eq!(p.prev_cursor, None);
+    }
+
+    #[test]
+    fn paginated_serde_round_trip() {
+        let mut page = Paginated::new(entries(), 5);
+        page.prev_cursor = Some(1);
+        let next_cursor = page.next_cursor.unwrap();
+
+        let json = serde_json::to_value(&page).unwrap();
+        assert_eq!(
+            json["next_cursor"],
+            PaginatedCursor(next_cursor).to_string()
+        );
+        assert_eq!(json["prev_cursor"], PaginatedCursor(1).to_string());
+        assert_eq!(json["limit"], 5);
+
+        let round_tripped: Paginated<Dummy> = serde_json::from_value(json).unwrap();
+        assert_eq!(round_tripped.entries, page.entries);
+        assert_eq!(round_tripped.next_cursor, Some(next_cursor));
+        assert_eq!(round_tripped.prev_cursor, Some(1));
+        assert_eq!(round_tripped.limit, 5);
+    }
+
+    #[test]
+    fn paginated_cursor_round_trip() {
+        let cursor = PaginatedCursor::new(10_i64);
+        let encoded = cursor.to_string();
+        assert_ne!(encoded, "10");
+
+        let decoded: PaginatedCursor<i64> = encoded.parse().unwrap();
+        assert_eq!(decoded, cursor);
+
+        let json = serde_json::to_string(&cursor).unwrap();
+        assert_eq!(json, format!("\"{encoded}\""));
+
+        let decoded: PaginatedCursor<i64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.into_inner(), 10);
+    }
+
+    #[test]
+    fn paginated_cursor_rejects_garbage() {
+        assert!(matches!(
+            "not base64!".parse::<PaginatedCursor<i64>>(),
+            Err(CursorError::Encoding { .. })
+        ));
+
+        let wrong_type = PaginatedCursor::new("foo").to_string();
+        assert!(matches!(
+            wrong_type.parse::<PaginatedCursor<i64>>(),
+            Err(CursorError::Value { .. })
+        ));
+    }
 }
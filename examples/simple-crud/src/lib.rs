@@ -36,6 +36,7 @@ pub struct Book {
     genre: Vec<Genre>,
     pages: i64,
     #[sqlx(json)]
+    #[select(json)]
     meta: Meta,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -47,7 +48,7 @@ pub struct BookCreate {
     title: String,
     author: String,
     #[insert(cust_opt)]
-    genre: Option<Genre>,
+    genre: Option<Vec<Genre>>,
     meta: Meta,
     pages: i64,
 }
@@ -60,7 +61,6 @@ pub struct BookUpdate {
     genre: Option<Genre>,
     meta: Option<Meta>,
     pages: Option<i64>,
-    updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Builder)]
@@ -70,6 +70,8 @@ pub struct BookFilters {
     #[builder(setter(strip_option), default)]
     cursor: Option<BookId>,
     #[builder(setter(strip_option), default)]
+    prev_cursor: Option<BookId>,
+    #[builder(setter(strip_option), default)]
     author: Option<String>,
     #[builder(setter(strip_option), default)]
     genre: Option<Genre>,
@@ -86,6 +88,14 @@ impl CursoredFilter for BookFilters {
         self.cursor = Some(cursor);
     }
 
+    fn prev_cursor(&self) -> Option<<Self::Entity as Cursored>::CursorType> {
+        self.prev_cursor
+    }
+
+    fn set_prev_cursor(&mut self, cursor: <Self::Entity as Cursored>::CursorType) {
+        self.prev_cursor = Some(cursor);
+    }
+
     fn page_limit(&self) -> usize {
         self.limit
     }
@@ -107,14 +117,104 @@ impl Filter for BookFilters {
 #[db_bmc(
     model = Book,
     id_type = BookId,
+    timestamps,
     methods(
         create = BookCreate,
+        create_many = BookCreate,
         get,
+        get_arc(vis = "pub"),
+        get_optional,
+        get_many,
+        get_many_optional,
+        get_many_by_ids,
+        find(BookFilters, vis = "pub"),
+        find_optional = BookFilters,
+        first,
+        last,
         list,
-        list_paginated = BookFilters,
+        list_ordered,
+        list_filtered = BookFilters,
+        list_paginated(filter = BookFilters, vis = "pub"),
+        list_by_cursor,
+        list_offset_paginated = BookFilters,
         update = BookUpdate,
+        update_conditional,
+        update_all_where = (data = BookUpdate, filter = BookFilters),
+        update_where = (BookUpdate, BookFilters),
+        upsert(BookCreate, BookUpdate, vis = "pub"),
+        upsert_by = (conflict_cols = [BookIden::Title, BookIden::Author], update_type = BookUpdate),
         delete,
+        delete_where = BookFilters,
+        batch_delete_where = BookFilters,
         count,
+        count_filtered = BookFilters,
+        exists,
+        exists_where = BookFilters,
     )
 )]
 pub struct BookBmc;
+
+#[cfg(test)]
+mod tests {
+    use bodega::Insert;
+    use sea_query::{PostgresQueryBuilder, Query};
+
+    use super::*;
+
+    fn insert_sql(genre: Option<Vec<Genre>>) -> String {
+        let create = BookCreate {
+            title: "Dune".into(),
+            author: "Frank Herbert".into(),
+            genre,
+            meta: Meta {
+                spine_size: 4,
+                book_weight: 900,
+            },
+            pages: 412,
+        };
+
+        Query::insert()
+            .into_table(BookIden::Table)
+            .columns(create.insert_cols())
+            .values_panic(create.insert_vals())
+            .to_string(PostgresQueryBuilder)
+    }
+
+    #[derive(Debug, Clone, Insert)]
+    #[insert(iden_enum = BookIden)]
+    struct CoverCreate {
+        #[insert(rename = "cover", cust_opt)]
+        cover: Option<Vec<u8>>,
+    }
+
+    fn cover_sql(cover: Option<Vec<u8>>) -> String {
+        let create = CoverCreate { cover };
+
+        Query::insert()
+            .into_table(BookIden::Table)
+            .columns(create.insert_cols())
+            .values_panic(create.insert_vals())
+            .to_string(PostgresQueryBuilder)
+    }
+
+    #[test]
+    fn nullable_bytea() {
+        assert_eq!(
+            cover_sql(None),
+            r#"INSERT INTO "book" ("cover") VALUES (NULL)"#
+        );
+        assert_eq!(
+            cover_sql(Some(vec![1, 2])),
+            r#"INSERT INTO "book" ("cover") VALUES ('\x0102')"#
+        );
+    }
+
+    #[test]
+    fn nullable_genre_arrays() {
+        assert!(insert_sql(None).contains("'Frank Herbert', NULL,"));
+        assert!(insert_sql(Some(vec![])).contains("'Frank Herbert', '{}',"));
+        assert!(insert_sql(Some(vec![Genre::Mystery, Genre::Fantasy])).contains(
+            r#"'Frank Herbert', ARRAY[ CAST('Mystery' AS "genre"), CAST('Fantasy' AS "genre") ],"#
+        ));
+    }
+}